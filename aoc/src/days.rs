@@ -0,0 +1,115 @@
+use std::env;
+use std::path::{Path, PathBuf};
+
+use common::{ParseError, TimedRun};
+
+pub type Solver = fn(&str) -> Result<TimedRun, ParseError>;
+
+pub const DAYS: [(u8, Solver); 6] = [
+    (1, common::solve_timed::<day_01::Day01>),
+    (2, common::solve_timed::<day_02::Day02>),
+    (3, common::solve_timed::<day_03::Day03>),
+    (4, common::solve_timed::<day_04::Day04>),
+    (5, common::solve_timed::<day_05::Day05>),
+    (6, common::solve_timed::<day_06::Day06>),
+];
+
+const INPUT_FILES: [&str; 2] = ["input.csv", "input.txt"];
+
+/// the recorded answers for each day's input
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+pub fn solver(day: u8) -> Option<Solver> {
+    DAYS.iter().find(|(d, _)| *d == day).map(|(_, s)| *s)
+}
+
+/// parses the day selection argument into an ordered list of day numbers
+pub fn parse_selection(selection: &str) -> Result<Vec<u8>, String> {
+    if selection == "all" {
+        return Ok(DAYS.iter().map(|(d, _)| *d).collect());
+    }
+
+    if let Some((from, to)) = selection.split_once("..") {
+        let to = to.strip_prefix('=').unwrap_or(to);
+        let from = parse_day(from)?;
+        let to = parse_day(to)?;
+        if from > to {
+            return Err(format!("invalid day range: {selection}"));
+        }
+        return Ok((from..=to).collect());
+    }
+
+    selection.split(',').map(parse_day).collect()
+}
+
+fn parse_day(value: &str) -> Result<u8, String> {
+    match value.trim().parse::<u8>() {
+        Ok(day) if (1..=25).contains(&day) => Ok(day),
+        _ => Err(format!("invalid day: {value}")),
+    }
+}
+
+/// the current directory when it contains the day folders, otherwise the
+/// workspace this binary was built from
+pub fn workspace_root() -> PathBuf {
+    if let Ok(cwd) = env::current_dir() {
+        if cwd.join("day_01").is_dir() {
+            return cwd;
+        }
+    }
+
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .map(Path::to_path_buf)
+        .unwrap_or_default()
+}
+
+pub fn day_dir(root: &Path, day: u8) -> PathBuf {
+    root.join(format!("day_{day:02}"))
+}
+
+/// returns the first default input file that exists for the day
+pub fn find_input(root: &Path, day: u8) -> Option<PathBuf> {
+    let dir = day_dir(root, day);
+    INPUT_FILES
+        .iter()
+        .map(|name| dir.join(name))
+        .find(|path| path.is_file())
+}
+
+/// A day's solver along with its default input, ready to be solved
+pub struct LoadedDay {
+    pub solve: Solver,
+    pub path: PathBuf,
+    pub input: String,
+}
+
+impl LoadedDay {
+    /// solves the loaded input, parse errors name the input file
+    pub fn solve(&self) -> Result<TimedRun, String> {
+        (self.solve)(&self.input).map_err(|err| err.with_file(&self.path).to_string())
+    }
+}
+
+/// finds and reads the day's default input, describing whatever went wrong
+pub fn load_day(root: &Path, day: u8) -> Result<LoadedDay, String> {
+    let Some(solve) = solver(day) else {
+        return Err(String::from("no solution"));
+    };
+
+    let Some(path) = find_input(root, day) else {
+        return Err(format!(
+            "no input file found in {}",
+            day_dir(root, day).display()
+        ));
+    };
+
+    let input = common::read_file(&path).map_err(|err| err.to_string())?;
+
+    Ok(LoadedDay { solve, path, input })
+}
+
+/// solves the day's default input once
+pub fn solve_day(root: &Path, day: u8) -> Result<TimedRun, String> {
+    load_day(root, day)?.solve()
+}
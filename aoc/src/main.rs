@@ -0,0 +1,161 @@
+use std::env;
+use std::path::Path;
+use std::process::ExitCode;
+
+mod days;
+mod timing;
+mod verify;
+
+// usage
+// aoc run 3        runs a single day
+// aoc run 1..6     runs an inclusive range of days
+// aoc run 1,3,5    runs a list of days
+// aoc run all      runs every day that has a solution
+// aoc verify all   checks the days against their answers.toml
+//
+// run options
+// --time           prints parse, part 1 and part 2 wall time per day
+// --repeat N       solves each day N times and prints min/median/max
+// --json           prints answers and timings as JSON instead
+
+/// the command line after the command name
+struct Options {
+    selection: String,
+    time: bool,
+    repeat: usize,
+    json: bool,
+}
+
+impl Options {
+    fn parse(args: &[String]) -> Result<Self, String> {
+        let mut options = Options {
+            selection: String::from("all"),
+            time: false,
+            repeat: 1,
+            json: false,
+        };
+        let mut args = args.iter();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--time" => options.time = true,
+                "--json" => options.json = true,
+                "--repeat" => {
+                    let value = args.next().ok_or("--repeat needs a count")?;
+                    options.repeat = match value.parse::<usize>() {
+                        Ok(n) if n > 0 => n,
+                        _ => return Err(format!("invalid repeat count: {value}")),
+                    };
+                }
+                flag if flag.starts_with("--") => return Err(format!("unknown option: {flag}")),
+                selection => options.selection = String::from(selection),
+            }
+        }
+
+        Ok(options)
+    }
+    fn timed(&self) -> bool {
+        self.time || self.json || self.repeat > 1
+    }
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let Some(command) = args.first() else {
+        print_usage();
+        return ExitCode::FAILURE;
+    };
+
+    let options = match Options::parse(&args[1..]) {
+        Ok(options) => options,
+        Err(msg) => {
+            eprintln!("{msg}");
+            print_usage();
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let days = match days::parse_selection(&options.selection) {
+        Ok(days) => days,
+        Err(msg) => {
+            eprintln!("{msg}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let root = days::workspace_root();
+    let ok = match command.as_str() {
+        "run" if options.timed() => run_timed(&root, &days, &options),
+        "run" => run(&root, &days),
+        "verify" => verify::verify(&root, &days),
+        _ => {
+            eprintln!("unknown command: {command}");
+            print_usage();
+            false
+        }
+    };
+
+    if ok {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn print_usage() {
+    eprintln!(
+        "usage: aoc <run|verify> <day|from..to|d1,d2,...|all> [--time] [--repeat N] [--json]"
+    );
+}
+
+/// prints both answers for each day, returns false if any day failed
+fn run(root: &Path, days: &[u8]) -> bool {
+    let mut ok = true;
+
+    for &day in days {
+        match days::solve_day(root, day) {
+            Ok(run) => {
+                println!("day {day:02} part 1: {}", run.part1);
+                println!("day {day:02} part 2: {}", run.part2);
+            }
+            Err(err) => {
+                eprintln!("day {day:02}: {err}");
+                ok = false;
+            }
+        }
+    }
+
+    ok
+}
+
+/// like `run` but also reports how long each stage took
+fn run_timed(root: &Path, days: &[u8], options: &Options) -> bool {
+    let mut ok = true;
+    let mut reports = Vec::new();
+
+    for &day in days {
+        match timing::measure(root, day, options.repeat) {
+            Ok(report) => {
+                if !options.json {
+                    println!("day {day:02} part 1: {}", report.part1);
+                    println!("day {day:02} part 2: {}", report.part2);
+                }
+                reports.push(report);
+            }
+            Err(err) => {
+                eprintln!("day {day:02}: {err}");
+                ok = false;
+            }
+        }
+    }
+
+    if options.json {
+        println!("{}", timing::to_json(&reports));
+    } else {
+        println!();
+        timing::print_table(&reports);
+    }
+
+    ok
+}
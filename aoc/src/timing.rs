@@ -0,0 +1,150 @@
+use std::path::Path;
+use std::time::Duration;
+
+use common::{json, Timings};
+
+use crate::days;
+
+/// min, median and max of a set of timing samples
+#[derive(Debug, Clone, Copy)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    /// panics when `samples` is empty
+    fn from_samples(mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+        let mid = samples.len() / 2;
+        let median = if samples.len().is_multiple_of(2) {
+            (samples[mid - 1] + samples[mid]) / 2
+        } else {
+            samples[mid]
+        };
+
+        Stats {
+            min: samples[0],
+            median,
+            max: samples[samples.len() - 1],
+        }
+    }
+    fn to_json(self) -> String {
+        format!(
+            "{{\"min_ns\": {}, \"median_ns\": {}, \"max_ns\": {}}}",
+            self.min.as_nanos(),
+            self.median.as_nanos(),
+            self.max.as_nanos()
+        )
+    }
+}
+
+/// the answers and timing statistics for one day
+pub struct DayReport {
+    pub day: u8,
+    pub part1: String,
+    pub part2: String,
+    pub runs: usize,
+    pub parse: Stats,
+    pub part1_time: Stats,
+    pub part2_time: Stats,
+    pub total: Stats,
+}
+
+/// solves the day `repeat` times, the input file is only read once
+pub fn measure(root: &Path, day: u8, repeat: usize) -> Result<DayReport, String> {
+    let loaded = days::load_day(root, day)?;
+    let mut samples: Vec<Timings> = Vec::with_capacity(repeat);
+    let mut answers: Option<(String, String)> = None;
+
+    for _ in 0..repeat.max(1) {
+        let run = loaded.solve()?;
+        samples.push(run.timings);
+        answers.get_or_insert((run.part1, run.part2));
+    }
+
+    let (part1, part2) = answers.unwrap_or_default();
+    let stats =
+        |stage: fn(&Timings) -> Duration| Stats::from_samples(samples.iter().map(stage).collect());
+
+    Ok(DayReport {
+        day,
+        part1,
+        part2,
+        runs: samples.len(),
+        parse: stats(|t| t.parse),
+        part1_time: stats(|t| t.part1),
+        part2_time: stats(|t| t.part2),
+        total: stats(Timings::total),
+    })
+}
+
+/// prints one row per day, or min/median/max rows when repeated
+pub fn print_table(reports: &[DayReport]) {
+    let repeated = reports.iter().any(|r| r.runs > 1);
+
+    if !repeated {
+        println!(
+            "{:<4} {:>12} {:>12} {:>12} {:>12}",
+            "day", "parse", "part 1", "part 2", "total"
+        );
+        for r in reports {
+            println!(
+                "{:<4} {:>12} {:>12} {:>12} {:>12}",
+                format!("{:02}", r.day),
+                format!("{:.2?}", r.parse.median),
+                format!("{:.2?}", r.part1_time.median),
+                format!("{:.2?}", r.part2_time.median),
+                format!("{:.2?}", r.total.median),
+            );
+        }
+        return;
+    }
+
+    println!(
+        "{:<4} {:<7} {:>6} {:>12} {:>12} {:>12}",
+        "day", "stage", "runs", "min", "median", "max"
+    );
+    for r in reports {
+        let stages = [
+            ("parse", r.parse),
+            ("part 1", r.part1_time),
+            ("part 2", r.part2_time),
+            ("total", r.total),
+        ];
+        for (stage, stats) in stages {
+            println!(
+                "{:<4} {:<7} {:>6} {:>12} {:>12} {:>12}",
+                format!("{:02}", r.day),
+                stage,
+                r.runs,
+                format!("{:.2?}", stats.min),
+                format!("{:.2?}", stats.median),
+                format!("{:.2?}", stats.max),
+            );
+        }
+    }
+}
+
+/// the reports as a JSON document, durations are in nanoseconds
+pub fn to_json(reports: &[DayReport]) -> String {
+    let days: Vec<String> = reports
+        .iter()
+        .map(|r| {
+            format!(
+                "    {{\"day\": {}, \"part1\": {}, \"part2\": {}, \"runs\": {}, \"parse\": {}, \"part1_time\": {}, \"part2_time\": {}, \"total\": {}}}",
+                r.day,
+                json::quote(&r.part1),
+                json::quote(&r.part2),
+                r.runs,
+                r.parse.to_json(),
+                r.part1_time.to_json(),
+                r.part2_time.to_json(),
+                r.total.to_json(),
+            )
+        })
+        .collect();
+
+    format!("{{\n  \"days\": [\n{}\n  ]\n}}", days.join(",\n"))
+}
@@ -0,0 +1,84 @@
+use std::path::Path;
+
+use common::Answers;
+
+use crate::days::{self, ANSWERS_FILE};
+
+/// the outcome of checking one part against its recorded answer
+enum Check {
+    Pass,
+    Fail,
+    /// nothing has been recorded for this part yet
+    Unknown,
+}
+
+impl Check {
+    fn label(&self) -> &'static str {
+        match self {
+            Check::Pass => "pass",
+            Check::Fail => "FAIL",
+            Check::Unknown => "unknown",
+        }
+    }
+}
+
+fn check(expected: Option<&String>, actual: &str) -> Check {
+    match expected {
+        Some(expected) if expected == actual => Check::Pass,
+        Some(_) => Check::Fail,
+        None => Check::Unknown,
+    }
+}
+
+/// compares each day's answers with its answers file and prints a table
+///
+/// returns false when any part failed or a day could not be checked
+pub fn verify(root: &Path, days: &[u8]) -> bool {
+    let mut ok = true;
+
+    println!(
+        "{:<4} {:<5} {:>16} {:>16}  result",
+        "day", "part", "expected", "actual"
+    );
+
+    for &day in days {
+        let label = format!("{day:02}");
+        let answers_path = days::day_dir(root, day).join(ANSWERS_FILE);
+        let answers = if answers_path.is_file() {
+            match Answers::load(&answers_path) {
+                Ok(answers) => answers,
+                Err(err) => {
+                    println!("{label:<4} {err}");
+                    ok = false;
+                    continue;
+                }
+            }
+        } else {
+            Answers::default()
+        };
+
+        let (part_1, part_2) = match days::solve_day(root, day) {
+            Ok(run) => (run.part1, run.part2),
+            Err(err) => {
+                println!("{label:<4} {err}");
+                ok = false;
+                continue;
+            }
+        };
+
+        for (part, expected, actual) in [(1, &answers.part1, part_1), (2, &answers.part2, part_2)] {
+            let result = check(expected.as_ref(), &actual);
+            if let Check::Fail = result {
+                ok = false;
+            }
+
+            println!(
+                "{label:<4} {part:<5} {:>16} {actual:>16}  {}",
+                expected.as_deref().unwrap_or("-"),
+                result.label()
+            );
+        }
+    }
+
+    ok
+}
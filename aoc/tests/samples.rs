@@ -0,0 +1,82 @@
+// runs every day against its puzzle sample inputs
+//
+// a sample is any `test*.txt` or `test*.csv` file in a day's folder that has
+// a matching `<name>.answers.toml` next to it, e.g. `test.txt` and
+// `test.answers.toml`. adding another sample is just a matter of dropping
+// both files into the folder.
+
+use std::fs::read_dir;
+use std::path::{Path, PathBuf};
+
+use common::{Answers, Solution};
+
+const SAMPLE_EXTENSIONS: [&str; 2] = ["txt", "csv"];
+
+/// returns (sample input, answers file) pairs for the day, sorted by name
+fn discover_samples(day_dir: &Path) -> Vec<(PathBuf, PathBuf)> {
+    let mut samples: Vec<(PathBuf, PathBuf)> = read_dir(day_dir)
+        .unwrap()
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| {
+            let is_sample = path
+                .file_stem()
+                .and_then(|s| s.to_str())
+                .is_some_and(|s| s.starts_with("test"));
+            let has_extension = path
+                .extension()
+                .and_then(|e| e.to_str())
+                .is_some_and(|e| SAMPLE_EXTENSIONS.contains(&e));
+
+            is_sample && has_extension
+        })
+        .filter_map(|input| {
+            let stem = input.file_stem()?.to_str()?;
+            let answers = input.with_file_name(format!("{stem}.answers.toml"));
+
+            answers.is_file().then_some((input, answers))
+        })
+        .collect();
+
+    samples.sort();
+    samples
+}
+
+fn check_samples<S: Solution>(day: &str) {
+    let day_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("..").join(day);
+    let samples = discover_samples(&day_dir);
+
+    assert!(!samples.is_empty(), "{day} has no samples");
+
+    for (input, answers) in samples {
+        let expected = Answers::load(&answers).unwrap();
+        let (part_1, part_2) = common::solve_file::<S>(&input).unwrap();
+        let name = input.file_name().unwrap().to_string_lossy();
+
+        if let Some(expected) = expected.part1 {
+            assert_eq!(part_1, expected, "{day}/{name} part 1");
+        }
+        if let Some(expected) = expected.part2 {
+            assert_eq!(part_2, expected, "{day}/{name} part 2");
+        }
+    }
+}
+
+macro_rules! samples {
+    ($($name:ident => $solution:ty),* $(,)?) => {
+        $(
+            #[test]
+            fn $name() {
+                check_samples::<$solution>(stringify!($name));
+            }
+        )*
+    };
+}
+
+samples! {
+    day_01 => day_01::Day01,
+    day_02 => day_02::Day02,
+    day_03 => day_03::Day03,
+    day_04 => day_04::Day04,
+    day_05 => day_05::Day05,
+    day_06 => day_06::Day06,
+}
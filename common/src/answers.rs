@@ -0,0 +1,57 @@
+use std::path::Path;
+
+use crate::{read_file, Error, ParseError};
+
+/// The recorded answers for one puzzle input
+///
+/// Stored as a small TOML style file:
+///
+/// ```toml
+/// part1 = 1889772
+/// part2 = 23228917
+/// ```
+///
+/// Values may be bare or quoted, `#` starts a comment and either part may be
+/// left out when it is not known yet.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl Answers {
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut answers = Answers::default();
+
+        for line in crate::lines(input) {
+            let text = line.text.split('#').next().unwrap_or("").trim();
+            if text.is_empty() {
+                continue;
+            }
+
+            let Some((key, value)) = text.split_once('=') else {
+                return Err(line.error(text, "expected `key = value`"));
+            };
+            let key = key.trim();
+            let value = value.trim();
+            let value = value
+                .strip_prefix('"')
+                .and_then(|v| v.strip_suffix('"'))
+                .unwrap_or(value);
+
+            match key {
+                "part1" => answers.part1 = Some(String::from(value)),
+                "part2" => answers.part2 = Some(String::from(value)),
+                _ => return Err(line.error(key, "expected `part1` or `part2`")),
+            }
+        }
+
+        Ok(answers)
+    }
+    /// reads the answers file at `path`, parse errors name the file
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        let input = read_file(path)?;
+
+        Answers::parse(&input).map_err(|err| Error::Parse(err.with_file(path)))
+    }
+}
@@ -0,0 +1,101 @@
+use std::fmt;
+use std::io;
+use std::path::PathBuf;
+
+/// A malformed token in a puzzle input
+///
+/// `line` and `column` are 1-based, `column` counts characters rather than
+/// bytes so it lines up with what an editor shows.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    file: Option<PathBuf>,
+    line: usize,
+    column: usize,
+    token: String,
+    message: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, token: &str, message: &str) -> Self {
+        ParseError {
+            file: None,
+            line,
+            column,
+            token: String::from(token),
+            message: String::from(message),
+        }
+    }
+    /// attaches the file the input was read from
+    pub fn with_file(mut self, file: impl Into<PathBuf>) -> Self {
+        self.file = Some(file.into());
+        self
+    }
+    pub fn file(&self) -> Option<&PathBuf> {
+        self.file.as_ref()
+    }
+    pub fn line(&self) -> usize {
+        self.line
+    }
+    pub fn column(&self) -> usize {
+        self.column
+    }
+    pub fn token(&self) -> &str {
+        &self.token
+    }
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.file {
+            Some(file) => write!(f, "{}:", file.display())?,
+            None => write!(f, "<input>:")?,
+        }
+        write!(
+            f,
+            "{}:{}: {} (found {:?})",
+            self.line, self.column, self.message, self.token
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Anything that can stop a day from producing its answers
+pub enum Error {
+    Io { path: PathBuf, source: io::Error },
+    Parse(ParseError),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io { path, source } => write!(f, "{}: {source}", path.display()),
+            Error::Parse(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+// `main` prints a returned error with its Debug form, so keep that readable
+impl fmt::Debug for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io { source, .. } => Some(source),
+            Error::Parse(err) => Some(err),
+        }
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(err: ParseError) -> Self {
+        Error::Parse(err)
+    }
+}
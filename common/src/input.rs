@@ -0,0 +1,38 @@
+use std::str::FromStr;
+
+use crate::ParseError;
+
+/// A line of puzzle input along with its 1-based line number
+#[derive(Debug, Clone, Copy)]
+pub struct Line<'a> {
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    /// 1-based character column of `token`, which must be a slice of this line
+    pub fn column_of(&self, token: &str) -> usize {
+        let offset = (token.as_ptr() as usize).saturating_sub(self.text.as_ptr() as usize);
+        let offset = offset.min(self.text.len());
+
+        self.text[..offset].chars().count() + 1
+    }
+    /// builds an error pointing at `token`
+    pub fn error(&self, token: &str, message: &str) -> ParseError {
+        ParseError::new(self.number, self.column_of(token), token, message)
+    }
+    /// parses `token`, a slice of this line, reporting where it sits on failure
+    pub fn parse<T: FromStr>(&self, token: &'a str) -> Result<T, ParseError> {
+        token
+            .parse()
+            .map_err(|_| self.error(token, "expected a number"))
+    }
+}
+
+/// iterates over the input's lines with their line numbers
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input.lines().enumerate().map(|(i, text)| Line {
+        number: i + 1,
+        text,
+    })
+}
@@ -0,0 +1,139 @@
+use std::fmt::Display;
+use std::fs::read_to_string;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+mod answers;
+mod error;
+mod input;
+pub mod json;
+
+pub use answers::Answers;
+pub use error::{Error, ParseError};
+pub use input::{lines, Line};
+
+/// A single day's puzzle solution
+///
+/// `parse` turns the raw puzzle input into the day's typed model once, then
+/// both parts are answered from that model.
+pub trait Solution {
+    type Input;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    /// builds the day's model from the raw puzzle input
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    /// answers part 1 from the parsed model
+    fn part1(input: &Self::Input) -> Self::Answer1;
+    /// answers part 2 from the parsed model
+    fn part2(input: &Self::Input) -> Self::Answer2;
+}
+
+/// parses the input and returns both answers formatted for printing
+pub fn solve<S: Solution>(input: &str) -> Result<(String, String), ParseError> {
+    let parsed = S::parse(input)?;
+
+    Ok((S::part1(&parsed).to_string(), S::part2(&parsed).to_string()))
+}
+
+/// Wall time spent in each stage of solving a day
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part1: Duration,
+    pub part2: Duration,
+}
+
+impl Timings {
+    pub fn total(&self) -> Duration {
+        self.parse + self.part1 + self.part2
+    }
+}
+
+/// both formatted answers along with how long each stage took
+#[derive(Debug, Clone)]
+pub struct TimedRun {
+    pub part1: String,
+    pub part2: String,
+    pub timings: Timings,
+}
+
+/// like `solve` but times the parse and each part separately
+///
+/// formatting the answers happens outside the timed sections
+pub fn solve_timed<S: Solution>(input: &str) -> Result<TimedRun, ParseError> {
+    let start = Instant::now();
+    let parsed = S::parse(input)?;
+    let parse = start.elapsed();
+
+    let start = Instant::now();
+    let part1 = S::part1(&parsed);
+    let part1_time = start.elapsed();
+
+    let start = Instant::now();
+    let part2 = S::part2(&parsed);
+    let part2_time = start.elapsed();
+
+    Ok(TimedRun {
+        part1: part1.to_string(),
+        part2: part2.to_string(),
+        timings: Timings {
+            parse,
+            part1: part1_time,
+            part2: part2_time,
+        },
+    })
+}
+
+/// reads and solves the input file at `path`, parse errors name the file
+pub fn solve_file<S: Solution>(path: &Path) -> Result<(String, String), Error> {
+    let input = read_file(path)?;
+
+    solve::<S>(&input).map_err(|err| Error::Parse(err.with_file(path)))
+}
+
+/// reads a whole input file
+pub fn read_file(path: &Path) -> Result<String, Error> {
+    read_to_string(path).map_err(|source| Error::Io {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+/// parses the file at `path`, or `example` when no path was given
+///
+/// for day binaries that need the parsed model rather than just the answers
+pub fn parse_input<S: Solution>(path: Option<&String>, example: &str) -> Result<S::Input, Error> {
+    let input = read_input(path, example)?;
+
+    S::parse(&input).map_err(|err| match path {
+        Some(p) => Error::Parse(err.with_file(p)),
+        None => Error::Parse(err),
+    })
+}
+
+/// reads the file at `path`, or returns `example` when no path was given
+pub fn read_input(path: Option<&String>, example: &str) -> Result<String, Error> {
+    match path {
+        Some(p) => read_file(Path::new(p)),
+        None => Ok(String::from(example)),
+    }
+}
+
+/// prints both answers the way every day binary does
+pub fn print_answers(part_1: impl Display, part_2: impl Display) {
+    println!("part 1 answer: {part_1}");
+    println!("part 2 answer: {part_2}");
+}
+
+/// entry point shared by the day binaries
+///
+/// solves the file at `path`, or `example` when no path was given, then
+/// prints both answers
+pub fn run<S: Solution>(path: Option<&String>, example: &str) -> Result<(), Error> {
+    let input = parse_input::<S>(path, example)?;
+
+    print_answers(S::part1(&input), S::part2(&input));
+
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+use day_01::{parse_lists, similarity_score, total_distance};
+
+const INPUT: &str = include_str!("../input.csv");
+
+/// `n` pseudo-random left/right pairs in the puzzle's five digit range
+fn synthetic_lists(n: usize) -> (Vec<i64>, Vec<i64>) {
+    let mut state: u64 = 0x2024_0001;
+    let mut next = || {
+        state = state.wrapping_mul(6364136223846793005).wrapping_add(1);
+        10_000 + (state >> 33) as i64 % 90_000
+    };
+
+    (0..n).map(|_| (next(), next())).unzip()
+}
+
+fn bench_day_01(c: &mut Criterion) {
+    let (l, r) = parse_lists(INPUT).unwrap();
+
+    c.bench_function("day_01/parse_lists/input", |b| {
+        b.iter(|| parse_lists(black_box(INPUT)))
+    });
+    c.bench_function("day_01/total_distance/input", |b| {
+        b.iter(|| total_distance(black_box(&l), black_box(&r)))
+    });
+    c.bench_function("day_01/similarity_score/input", |b| {
+        b.iter(|| similarity_score(black_box(&l), black_box(&r)))
+    });
+
+    let mut group = c.benchmark_group("day_01/synthetic");
+    for n in [1_000, 10_000, 100_000] {
+        let (l, r) = synthetic_lists(n);
+        group.bench_with_input(BenchmarkId::new("total_distance", n), &n, |b, _| {
+            b.iter(|| total_distance(black_box(&l), black_box(&r)))
+        });
+        group.bench_with_input(BenchmarkId::new("similarity_score", n), &n, |b, _| {
+            b.iter(|| similarity_score(black_box(&l), black_box(&r)))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_day_01);
+criterion_main!(benches);
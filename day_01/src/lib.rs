@@ -0,0 +1,123 @@
+use std::collections::HashMap;
+
+use common::{ParseError, Solution};
+
+pub mod matrix;
+mod metric;
+mod reader;
+pub mod report;
+pub mod stream;
+
+pub use metric::{Metric, Weight};
+pub use reader::{fields, read_columns, read_lists, Columns, Lists};
+
+/// the generated fallback lists used when no input file is given
+pub const EXAMPLE: &str = include_str!("../test.csv");
+
+/// Day 1: Historian Hysteria
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = (Vec<i64>, Vec<i64>);
+    type Answer1 = u128;
+    type Answer2 = i128;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lists(input)
+    }
+
+    fn part1((l, r): &Self::Input) -> u128 {
+        total_distance(l, r)
+    }
+
+    fn part2((l, r): &Self::Input) -> i128 {
+        similarity_score(l, r)
+    }
+}
+
+/// sums the distance between the smallest, second smallest, etc. values of
+/// the two lists
+pub fn total_distance(l: &[i64], r: &[i64]) -> u128 {
+    total_distance_with(l, r, Metric::Absolute)
+}
+
+/// like `total_distance` but scores each pair with `metric`
+pub fn total_distance_with(l: &[i64], r: &[i64], metric: Metric) -> u128 {
+    // answer_1 solution algo
+    // once we have the two lists, we want to sequentially diff the smallest numbers
+    // numbers in each list. the diff should always be positive, so in stead of
+    // determining which side is higher, we'll just use the absolute value of the
+    // subtraction result and always do "left - right".
+    // - sort the lists in ascending order
+    // - iterate from 0 to list.len()
+    // - result should be accumulated as "result += abs(l[i] - r[i])", or
+    //   whatever other metric was asked for
+
+    let mut l = l.to_vec();
+    let mut r = r.to_vec();
+
+    l.sort();
+    r.sort();
+    let mut ans_1: u128 = 0;
+    for i in 0..l.len() {
+        let left = *l.get(i).unwrap();
+        let right = *r.get(i).unwrap();
+        ans_1 += metric.distance(left, right);
+    }
+
+    ans_1
+}
+
+/// sums each left list value multiplied by the number of times it appears in
+/// the right list
+pub fn similarity_score(l: &[i64], r: &[i64]) -> i128 {
+    similarity_score_with(l, r, Weight::Product)
+}
+
+/// like `similarity_score` but scores each left value and its count in the
+/// right list with `weight`
+pub fn similarity_score_with(l: &[i64], r: &[i64], weight: Weight) -> i128 {
+    // answer_2 solution algo
+    // using hashmaps for the left and right lists, we track the occurrences of values
+    // in the left and right lists. once all items in left and right list have been
+    // iterated, we go through all the keys in the left list, and if they also appear
+    // in the right list, we add the weight of "value, occurrences" (by default
+    // "value * occurrences") to an accumulator, once for every time the key appears
+    // in the left list.
+
+    let mut l_similarity: HashMap<i64, usize> = HashMap::new();
+    let mut r_similarity: HashMap<i64, usize> = HashMap::new();
+
+    for (&left, &right) in l.iter().zip(r.iter()) {
+        *l_similarity.entry(left).or_insert(0) += 1;
+        match r_similarity.get(&right) {
+            Some(count) => r_similarity.insert(right, count + 1),
+            None => r_similarity.insert(right, 1),
+        };
+    }
+
+    let mut ans_2: i128 = 0;
+
+    for (k, &l_count) in &l_similarity {
+        if let Some(&count) = r_similarity.get(k) {
+            ans_2 += l_count as i128 * weight.weight(*k, count);
+        }
+    }
+
+    ans_2
+}
+
+/// parses the left and right location id lists, one pair per line
+///
+/// the columns can be separated by any mix of spaces and tabs, or by commas,
+/// and the input can start with a header row. values can be negative. the
+/// first line that can't be read is an error, see `read_lists` to carry on
+/// past bad lines instead
+pub fn parse_lists(input: &str) -> Result<(Vec<i64>, Vec<i64>), ParseError> {
+    let lists = read_lists(input);
+
+    match lists.rejected.into_iter().next() {
+        Some(err) => Err(err),
+        None => Ok((lists.left, lists.right)),
+    }
+}
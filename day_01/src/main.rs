@@ -1,94 +1,243 @@
-use std::collections::HashMap;
 use std::env;
-use std::fs::read_to_string;
-
-// references
-// https://doc.rust-lang.org/rust-by-example/std_misc/file/read_lines.html
-
-fn main() {
-    let args: Vec<String> = env::args().collect();
-    let (mut l, mut r) = get_input(args.get(1));
-    let mut l_similarity: HashMap<usize, usize> = HashMap::new();
-    let mut r_similarity: HashMap<usize, usize> = HashMap::new();
-
-    // answer_1 solution algo
-    // once we have the two lists, we want to sequentially diff the smallest numbers
-    // numbers in each list. the diff should always be positive, so in stead of
-    // determining which side is higher, we'll just use the absolute value of the
-    // subtraction result and always do "left - right".
-    // - sort the lists in ascending order
-    // - iterate from 0 to list.len()
-    // - result should be accumulated as "result += abs(l[i] - r[i])"
-    //
-    // answer_2 solution algo
-    // using hashmaps for the left and right lists, we track the occurrences of values
-    // in the left and right lists. for the left list, we only need to add a key entry
-    // but for the right list we need to accumulate the number of occurrences. once
-    // all items in left and right list have been iterated, we go through all the
-    // keys in the left list, and if they also appear in the right list, we add
-    // the product of "value * occurrences" to an accumulator.
-
-    l.sort();
-    r.sort();
-    let mut ans_1: usize = 0;
-    for i in 0..l.len() {
-        let left = *l.get(i).unwrap();
-        let right = *r.get(i).unwrap();
-        let diff = left.abs_diff(right);
-        ans_1 += diff;
-        println!("left: {left}, right: {right}, diff: {diff}");
-
-        l_similarity.entry(left).or_insert(0);
-        match r_similarity.get(&right) {
-            Some(count) => r_similarity.insert(right, count + 1),
-            None => r_similarity.insert(right, 1),
-        };
+use std::path::Path;
+use std::process;
+
+use day_01::stream::{self, DEFAULT_CHUNK_LEN};
+use day_01::{matrix, report, Columns, Day01, Metric, Weight, EXAMPLE};
+
+// usage
+// day_01 [input] [--lenient] [--matrix [table|csv]] [--stream [--chunk N]]
+//        [--metric METRIC] [--weight WEIGHT] [--report [csv|json]]
+//
+// --lenient        skips lines that aren't a full row of numbers instead of
+//                  stopping at the first one, each skipped line is reported
+// --matrix FORMAT  reads any number of columns and prints the part 1
+//                  distance and part 2 similarity between every pair of them,
+//                  as an aligned table (the default) or as CSV
+// --stream         reads the input a line at a time instead of all at once,
+//                  sorting the lists for part 1 through temporary files so
+//                  inputs bigger than memory still work
+// --chunk N        how many values of each list --stream sorts in memory
+//                  before spilling a run to disk, 1048576 by default
+// --metric METRIC  how part 1 scores each pair: absolute (the default),
+//                  squared, or capped:N for the absolute difference but at
+//                  most N
+// --weight WEIGHT  how part 2 scores a left value found `count` times on the
+//                  right, once for each time it is on the left: product
+//                  (value * count, the default), count, presence (value
+//                  whatever the count) or squared (value * count²)
+// --report FORMAT  prints every sorted pair with its diff and running total
+//                  and every distinct left value with its counts and what it
+//                  adds to part 2, as two CSV tables (the default) or as JSON
+
+/// how `--matrix` prints
+#[derive(PartialEq, Eq)]
+enum Format {
+    Table,
+    Csv,
+}
+
+/// how `--report` prints
+enum ReportFormat {
+    Csv,
+    Json,
+}
+
+fn usage_error(msg: &str) -> ! {
+    eprintln!("{msg}");
+    process::exit(2);
+}
+
+fn main() -> Result<(), common::Error> {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let mut path: Option<&String> = None;
+    let mut lenient = false;
+    let mut matrix_format: Option<Format> = None;
+    let mut streaming = false;
+    let mut chunk_len = DEFAULT_CHUNK_LEN;
+    let mut metric = Metric::default();
+    let mut weight = Weight::default();
+    let mut report_format: Option<ReportFormat> = None;
+
+    let mut args = args.iter().peekable();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--lenient" => lenient = true,
+            "--matrix" => {
+                let format = args.next_if(|a| *a == "csv" || *a == "table");
+                matrix_format = Some(match format.map(String::as_str) {
+                    Some("csv") => Format::Csv,
+                    _ => Format::Table,
+                });
+            }
+            "--stream" => streaming = true,
+            "--chunk" => {
+                chunk_len = match args.next().and_then(|n| n.parse().ok()) {
+                    Some(n) if n > 0 => n,
+                    _ => usage_error("--chunk expects a positive number"),
+                }
+            }
+            "--metric" => {
+                let value = args.next().map(|m| m.parse());
+                metric = match value {
+                    Some(Ok(m)) => m,
+                    Some(Err(msg)) => usage_error(&msg),
+                    None => usage_error("--metric needs absolute, squared or capped:N"),
+                }
+            }
+            "--weight" => {
+                let value = args.next().map(|w| w.parse());
+                weight = match value {
+                    Some(Ok(w)) => w,
+                    Some(Err(msg)) => usage_error(&msg),
+                    None => usage_error("--weight needs product, count, presence or squared"),
+                }
+            }
+            "--report" => {
+                let format = args.next_if(|a| *a == "csv" || *a == "json");
+                report_format = Some(match format.map(String::as_str) {
+                    Some("json") => ReportFormat::Json,
+                    _ => ReportFormat::Csv,
+                });
+            }
+            flag if flag.starts_with("--") => usage_error(&format!("unknown option: {flag}")),
+            _ => path = Some(arg),
+        }
+    }
+
+    if matrix_format.is_some() && report_format.is_some() {
+        usage_error("--report can't be combined with --matrix");
+    }
+    if streaming {
+        if matrix_format.is_some() || report_format.is_some() {
+            usage_error("--stream can't be combined with --matrix or --report");
+        }
+        return run_streaming(path, lenient, chunk_len, metric, weight);
+    }
+
+    let puzzle_scoring = metric == Metric::default() && weight == Weight::default();
+    let quiet = matrix_format.is_none() && report_format.is_none();
+    if !lenient && quiet && puzzle_scoring {
+        return common::run::<Day01>(path, EXAMPLE);
     }
 
-    let mut ans_2: usize = 0;
+    let input = common::read_input(path, EXAMPLE)?;
+    let width = if matrix_format.is_some() {
+        None
+    } else {
+        Some(2)
+    };
+    let table = day_01::read_columns(&input, width);
 
-    for k in l_similarity.keys() {
-        if let Some(count) = r_similarity.get(k) {
-            ans_2 += k * count;
-            println!("{k} occured {count} times in right list")
+    if !lenient {
+        if let Some(err) = table.rejected.first() {
+            return Err(match path {
+                Some(p) => common::Error::Parse(err.clone().with_file(p)),
+                None => common::Error::Parse(err.clone()),
+            });
         }
     }
+    report_rejected(&table, path);
+
+    match (matrix_format, report_format) {
+        (Some(format), _) => print_matrices(&table, format, metric, weight),
+        (None, Some(format)) => print_report(&table, format, metric, weight),
+        (None, None) => common::print_answers(
+            day_01::total_distance_with(&table.columns[0], &table.columns[1], metric),
+            day_01::similarity_score_with(&table.columns[0], &table.columns[1], weight),
+        ),
+    }
 
-    println!("part 1 answer: {ans_1}");
-    println!("part 2 answer: {ans_2}");
+    Ok(())
 }
 
-fn get_input(path: Option<&String>) -> (Vec<usize>, Vec<usize>) {
-    let mut l: Vec<usize> = Vec::new();
-    let mut r: Vec<usize> = Vec::new();
-    if let Some(p) = path {
-        // as mentioned in the read_lines rust-by-example "More efficient approach"
-        // there are more efficient ways to do this with File and Buf, but
-        // that includes more advanced rust than what i currently comprehend
-        let lines: Vec<String> = read_to_string(p)
-            .unwrap()
-            .lines()
-            .map(String::from)
-            .collect();
-
-        for line in lines.iter() {
-            let split: Vec<&str> = line.split(" ").collect();
-            if split.len() != 4 {
-                dbg!(split);
-                println!("line split does not contain 4 things");
-                continue;
-            }
-            // unwrapping like this feels bad
-            l.push(split.first().unwrap().parse().unwrap());
-            r.push(split.last().unwrap().parse().unwrap());
+/// answers both parts with `--stream`, reporting skipped lines as they come
+fn run_streaming(
+    path: Option<&String>,
+    lenient: bool,
+    chunk_len: usize,
+    metric: Metric,
+    weight: Weight,
+) -> Result<(), common::Error> {
+    let mut skipped = 0;
+    let on_reject = |err: common::ParseError| {
+        if !lenient {
+            return Err(err);
         }
-    } else {
-        // just generate some data to use
-        for i in 0..10 {
-            l.push(i + 1);
-            r.push(10 - i);
+        eprintln!("skipped {err}");
+        skipped += 1;
+        Ok(())
+    };
+
+    let answers = match path {
+        Some(p) => stream::stream_file(Path::new(p), chunk_len, metric, weight, on_reject)?,
+        None => stream::stream_lists(
+            EXAMPLE.as_bytes(),
+            None,
+            chunk_len,
+            metric,
+            weight,
+            on_reject,
+        )?,
+    };
+    if skipped > 0 {
+        eprintln!("skipped {skipped} lines");
+    }
+
+    common::print_answers(answers.distance, answers.similarity);
+
+    Ok(())
+}
+
+/// lists the lines `--lenient` skipped on stderr
+fn report_rejected(table: &Columns, path: Option<&String>) {
+    for err in &table.rejected {
+        match path {
+            Some(p) => eprintln!("skipped {}", err.clone().with_file(p)),
+            None => eprintln!("skipped {err}"),
         }
     }
+    if !table.rejected.is_empty() {
+        eprintln!("skipped {} lines", table.rejected.len());
+    }
+}
+
+fn print_report(table: &Columns, format: ReportFormat, metric: Metric, weight: Weight) {
+    let (l, r) = (&table.columns[0], &table.columns[1]);
+    let pairs = report::pair_rows(l, r, metric);
+    let similarity = report::similarity_rows(l, r, weight);
 
-    (l, r)
+    match format {
+        ReportFormat::Csv => {
+            print!("{}", report::pairs_to_csv(&pairs));
+            println!();
+            print!("{}", report::similarity_to_csv(&similarity));
+        }
+        ReportFormat::Json => print!("{}", report::report_to_json(&pairs, &similarity)),
+    }
+}
+
+fn print_matrices(table: &Columns, format: Format, metric: Metric, weight: Weight) {
+    let names = table.names();
+    let distance = matrix::distance_matrix(&table.columns, metric);
+    let similarity = matrix::similarity_matrix(&table.columns, weight);
+
+    match format {
+        Format::Table => {
+            print!(
+                "{}",
+                matrix::format_table("part 1 distance", &names, &distance)
+            );
+            println!();
+            print!(
+                "{}",
+                matrix::format_table("part 2 similarity", &names, &similarity)
+            );
+        }
+        Format::Csv => {
+            print!("{}", matrix::csv_header(&names));
+            print!("{}", matrix::format_csv("distance", &names, &distance));
+            print!("{}", matrix::format_csv("similarity", &names, &similarity));
+        }
+    }
 }
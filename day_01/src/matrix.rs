@@ -0,0 +1,74 @@
+use std::fmt::Display;
+
+use crate::{similarity_score_with, total_distance_with, Metric, Weight};
+
+/// `total_distance_with` between every pair of columns, row `i` column `j`
+/// pairs column `i` on the left with column `j` on the right
+pub fn distance_matrix(columns: &[Vec<i64>], metric: Metric) -> Vec<Vec<u128>> {
+    pairwise(columns, |l, r| total_distance_with(l, r, metric))
+}
+
+/// `similarity_score_with` between every pair of columns, row `i` column `j`
+/// pairs column `i` on the left with column `j` on the right
+///
+/// with the puzzle's `value * count` weight the similarity is symmetric
+/// like the distance, other weights generally aren't
+pub fn similarity_matrix(columns: &[Vec<i64>], weight: Weight) -> Vec<Vec<i128>> {
+    pairwise(columns, |l, r| similarity_score_with(l, r, weight))
+}
+
+fn pairwise<T>(columns: &[Vec<i64>], f: impl Fn(&[i64], &[i64]) -> T) -> Vec<Vec<T>> {
+    columns
+        .iter()
+        .map(|left| columns.iter().map(|right| f(left, right)).collect())
+        .collect()
+}
+
+/// a matrix as an aligned table with the column names along both edges
+pub fn format_table<T: Display>(title: &str, names: &[String], matrix: &[Vec<T>]) -> String {
+    let cells: Vec<Vec<String>> = matrix
+        .iter()
+        .map(|row| row.iter().map(T::to_string).collect())
+        .collect();
+    let label_width = names.iter().map(|n| n.len()).max().unwrap_or(0);
+    let width = names
+        .iter()
+        .map(String::len)
+        .chain(cells.iter().flatten().map(String::len))
+        .max()
+        .unwrap_or(0);
+
+    let mut table = format!("{title}\n{:label_width$}", "");
+    for name in names {
+        table.push_str(&format!("  {name:>width$}"));
+    }
+    table.push('\n');
+
+    for (name, row) in names.iter().zip(&cells) {
+        table.push_str(&format!("{name:label_width$}"));
+        for cell in row {
+            table.push_str(&format!("  {cell:>width$}"));
+        }
+        table.push('\n');
+    }
+
+    table
+}
+
+/// the header line for `format_csv` rows
+pub fn csv_header(names: &[String]) -> String {
+    format!("metric,column,{}\n", names.join(","))
+}
+
+/// a matrix as CSV rows, each starting with the metric and the row's column
+/// name
+pub fn format_csv<T: Display>(metric: &str, names: &[String], matrix: &[Vec<T>]) -> String {
+    let mut csv = String::new();
+
+    for (name, row) in names.iter().zip(matrix) {
+        let values: Vec<String> = row.iter().map(T::to_string).collect();
+        csv.push_str(&format!("{metric},{name},{}\n", values.join(",")));
+    }
+
+    csv
+}
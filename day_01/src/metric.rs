@@ -0,0 +1,110 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// How part 1 scores the gap between a left and a right value
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Metric {
+    /// the puzzle's `|left - right|`
+    #[default]
+    Absolute,
+    /// `(left - right)²`, so a few big gaps outweigh many small ones
+    Squared,
+    /// `|left - right|` but never more than the cap, so a few outliers
+    /// can't dominate the total
+    Capped(u64),
+}
+
+impl Metric {
+    pub fn distance(self, left: i64, right: i64) -> u128 {
+        let diff = left.abs_diff(right);
+        match self {
+            Metric::Absolute => diff as u128,
+            Metric::Squared => diff as u128 * diff as u128,
+            Metric::Capped(cap) => diff.min(cap) as u128,
+        }
+    }
+}
+
+impl FromStr for Metric {
+    type Err = String;
+
+    /// `absolute`, `squared` or `capped:N`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "absolute" => return Ok(Metric::Absolute),
+            "squared" => return Ok(Metric::Squared),
+            _ => {}
+        }
+
+        s.strip_prefix("capped:")
+            .and_then(|cap| cap.parse().ok())
+            .map(Metric::Capped)
+            .ok_or_else(|| format!("invalid metric: {s}, expected absolute, squared or capped:N"))
+    }
+}
+
+impl fmt::Display for Metric {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Metric::Absolute => write!(f, "absolute"),
+            Metric::Squared => write!(f, "squared"),
+            Metric::Capped(cap) => write!(f, "capped:{cap}"),
+        }
+    }
+}
+
+/// How part 2 scores a left value that turns up `count` times in the right
+/// list, added once for each time the value is in the left list
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Weight {
+    /// the puzzle's `value * count`
+    #[default]
+    Product,
+    /// just `count`, how many matches there are whatever the values
+    Count,
+    /// `value` if it appears at all, so once per left occurrence however
+    /// many matches it has
+    Presence,
+    /// `value * count²`, favouring values that repeat a lot
+    Squared,
+}
+
+impl Weight {
+    pub fn weight(self, value: i64, count: usize) -> i128 {
+        let (value, count) = (value as i128, count as i128);
+        match self {
+            Weight::Product => value * count,
+            Weight::Count => count,
+            Weight::Presence if count > 0 => value,
+            Weight::Presence => 0,
+            Weight::Squared => value * count * count,
+        }
+    }
+}
+
+impl FromStr for Weight {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "product" => Ok(Weight::Product),
+            "count" => Ok(Weight::Count),
+            "presence" => Ok(Weight::Presence),
+            "squared" => Ok(Weight::Squared),
+            _ => Err(format!(
+                "invalid weight: {s}, expected product, count, presence or squared"
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Weight {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Weight::Product => write!(f, "product"),
+            Weight::Count => write!(f, "count"),
+            Weight::Presence => write!(f, "presence"),
+            Weight::Squared => write!(f, "squared"),
+        }
+    }
+}
@@ -0,0 +1,170 @@
+use common::{Line, ParseError};
+
+/// The two location id lists and every line that couldn't be read into them
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Lists {
+    pub left: Vec<i64>,
+    pub right: Vec<i64>,
+    /// the column names from the header row, if the input starts with one
+    pub header: Option<Vec<String>>,
+    /// lines that are neither blank, a header nor a pair of numbers, in
+    /// input order
+    pub rejected: Vec<ParseError>,
+}
+
+/// splits a line into its fields, on commas when there are any and on runs
+/// of spaces and tabs otherwise
+///
+/// comma separated fields are trimmed but kept when empty, so a missing
+/// value is reported rather than the next column sliding into its place
+pub fn fields(text: &str) -> Vec<&str> {
+    if text.contains(',') {
+        text.split(',').map(str::trim).collect()
+    } else {
+        text.split_whitespace().collect()
+    }
+}
+
+/// a header row has no numbers in it at all, e.g. `left,right`
+fn is_header(fields: &[&str]) -> bool {
+    fields.iter().all(|field| field.parse::<f64>().is_err())
+}
+
+/// Every column of a list file and every line that couldn't be read into
+/// them
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Columns {
+    pub columns: Vec<Vec<i64>>,
+    /// the column names from the header row, if the input starts with one
+    pub header: Option<Vec<String>>,
+    /// lines that are neither blank, a header nor a full row of numbers, in
+    /// input order
+    pub rejected: Vec<ParseError>,
+}
+
+impl Columns {
+    /// the header's column names, or `col1`, `col2`, ... when there is no
+    /// header or it doesn't match the columns
+    pub fn names(&self) -> Vec<String> {
+        match &self.header {
+            Some(header) if header.len() == self.columns.len() => header.clone(),
+            _ => (1..=self.columns.len())
+                .map(|i| format!("col{i}"))
+                .collect(),
+        }
+    }
+}
+
+/// reads a row of exactly `width` numbers
+fn read_row(line: &Line, fields: &[&str], width: usize) -> Result<Vec<i64>, ParseError> {
+    if fields.len() != width {
+        let message = format!("expected {width} columns, found {}", fields.len());
+        return Err(line.error(line.text.trim(), &message));
+    }
+
+    fields.iter().map(|field| line.parse(field)).collect()
+}
+
+/// Turns lines into rows one at a time
+///
+/// Blank lines are skipped and a row with no numbers in it before the first
+/// data row is taken as the header. There is only ever one header, any
+/// further rows without numbers are rejected like any other bad row. With no
+/// fixed width the header decides how many columns there are, or the first
+/// data row when there's no header.
+#[derive(Debug, Clone, Default)]
+pub struct RowReader {
+    width: Option<usize>,
+    before_data: bool,
+    header: Option<Vec<String>>,
+}
+
+impl RowReader {
+    pub fn new(width: Option<usize>) -> Self {
+        RowReader {
+            width,
+            before_data: true,
+            header: None,
+        }
+    }
+    pub fn width(&self) -> Option<usize> {
+        self.width
+    }
+    /// the column names from the header row, if there was one
+    pub fn header(&self) -> Option<&Vec<String>> {
+        self.header.as_ref()
+    }
+    /// the row on `line`, None for a blank or header line
+    pub fn read(&mut self, line: &Line) -> Option<Result<Vec<i64>, ParseError>> {
+        // a byte order mark some editors put at the start of CSV files
+        let text = match line.number {
+            1 => line.text.trim_start_matches('\u{feff}'),
+            _ => line.text,
+        };
+        let line = Line {
+            number: line.number,
+            text,
+        };
+
+        let fields = fields(line.text);
+        if fields.is_empty() {
+            return None;
+        }
+
+        if self.before_data && self.header.is_none() && is_header(&fields) {
+            self.header = Some(fields.iter().map(|f| String::from(*f)).collect());
+            return None;
+        }
+        self.before_data = false;
+
+        let header = &self.header;
+        let width = *self
+            .width
+            .get_or_insert_with(|| header.as_ref().map_or(fields.len(), |h| h.len()));
+
+        Some(read_row(&line, &fields, width))
+    }
+}
+
+/// reads every column, keeping going past bad lines
+///
+/// every line that isn't blank or a header either adds a row or ends up in
+/// `rejected`, nothing is dropped without a trace. see `RowReader` for how
+/// the header and the number of columns are worked out
+pub fn read_columns(input: &str, width: Option<usize>) -> Columns {
+    let mut rows = RowReader::new(width);
+    let mut table = Columns::default();
+
+    for line in common::lines(input) {
+        match rows.read(&line) {
+            Some(Ok(row)) => {
+                table.columns.resize_with(row.len(), Vec::new);
+                for (column, value) in table.columns.iter_mut().zip(row) {
+                    column.push(value);
+                }
+            }
+            Some(Err(err)) => table.rejected.push(err),
+            None => {}
+        }
+    }
+
+    table
+        .columns
+        .resize_with(rows.width().unwrap_or(0), Vec::new);
+    table.header = rows.header().cloned();
+
+    table
+}
+
+/// reads both lists, keeping going past bad lines, see `read_columns`
+pub fn read_lists(input: &str) -> Lists {
+    let table = read_columns(input, Some(2));
+    let mut columns = table.columns.into_iter();
+
+    Lists {
+        left: columns.next().unwrap_or_default(),
+        right: columns.next().unwrap_or_default(),
+        header: table.header,
+        rejected: table.rejected,
+    }
+}
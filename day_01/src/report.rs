@@ -0,0 +1,143 @@
+use std::collections::{BTreeMap, HashMap};
+
+use crate::{Metric, Weight};
+
+/// One pair of part 1, the `index`th smallest value of each list
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PairRow {
+    /// 0-based position in the sorted lists
+    pub index: usize,
+    pub left: i64,
+    pub right: i64,
+    /// the pair's score under the metric
+    pub diff: u128,
+    /// the sum of `diff` up to and including this pair, the part 1 answer
+    /// on the last row
+    pub total: u128,
+}
+
+/// One distinct left list value of part 2 and what it adds to the score
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SimilarityRow {
+    pub value: i64,
+    pub left_count: usize,
+    pub right_count: usize,
+    /// the value's weight once for each time it is in the left list, the
+    /// contributions add up to the part 2 answer
+    pub contribution: i128,
+}
+
+/// every pair `total_distance_with` adds up, in sorted order
+pub fn pair_rows(l: &[i64], r: &[i64], metric: Metric) -> Vec<PairRow> {
+    let mut l = l.to_vec();
+    let mut r = r.to_vec();
+    l.sort();
+    r.sort();
+
+    let mut total = 0;
+    l.into_iter()
+        .zip(r)
+        .enumerate()
+        .map(|(index, (left, right))| {
+            let diff = metric.distance(left, right);
+            total += diff;
+            PairRow {
+                index,
+                left,
+                right,
+                diff,
+                total,
+            }
+        })
+        .collect()
+}
+
+/// every distinct left value `similarity_score_with` looks up, smallest
+/// first
+pub fn similarity_rows(l: &[i64], r: &[i64], weight: Weight) -> Vec<SimilarityRow> {
+    let mut left_counts: BTreeMap<i64, usize> = BTreeMap::new();
+    let mut right_counts: HashMap<i64, usize> = HashMap::new();
+    for (&left, &right) in l.iter().zip(r) {
+        *left_counts.entry(left).or_insert(0) += 1;
+        *right_counts.entry(right).or_insert(0) += 1;
+    }
+
+    left_counts
+        .into_iter()
+        .map(|(value, left_count)| {
+            let right_count = right_counts.get(&value).copied().unwrap_or(0);
+            let contribution = match right_count {
+                0 => 0,
+                n => left_count as i128 * weight.weight(value, n),
+            };
+            SimilarityRow {
+                value,
+                left_count,
+                right_count,
+                contribution,
+            }
+        })
+        .collect()
+}
+
+/// the pairs as CSV with a header line
+pub fn pairs_to_csv(rows: &[PairRow]) -> String {
+    let mut csv = String::from("index,left,right,diff,total\n");
+
+    for row in rows {
+        csv.push_str(&format!(
+            "{},{},{},{},{}\n",
+            row.index, row.left, row.right, row.diff, row.total
+        ));
+    }
+
+    csv
+}
+
+/// the similarity table as CSV with a header line
+pub fn similarity_to_csv(rows: &[SimilarityRow]) -> String {
+    let mut csv = String::from("value,left_count,right_count,contribution\n");
+
+    for row in rows {
+        csv.push_str(&format!(
+            "{},{},{},{}\n",
+            row.value, row.left_count, row.right_count, row.contribution
+        ));
+    }
+
+    csv
+}
+
+fn pair_to_json(row: &PairRow) -> String {
+    format!(
+        "{{\"index\": {}, \"left\": {}, \"right\": {}, \"diff\": {}, \"total\": {}}}",
+        row.index, row.left, row.right, row.diff, row.total
+    )
+}
+
+fn similarity_to_json(row: &SimilarityRow) -> String {
+    format!(
+        "{{\"value\": {}, \"left_count\": {}, \"right_count\": {}, \"contribution\": {}}}",
+        row.value, row.left_count, row.right_count, row.contribution
+    )
+}
+
+/// both answers, the pairs and the similarity table as one JSON document
+pub fn report_to_json(pairs: &[PairRow], similarity: &[SimilarityRow]) -> String {
+    let part_1 = pairs.last().map_or(0, |row| row.total);
+    let part_2: i128 = similarity.iter().map(|row| row.contribution).sum();
+    let pairs: Vec<String> = pairs
+        .iter()
+        .map(|row| format!("    {}", pair_to_json(row)))
+        .collect();
+    let similarity: Vec<String> = similarity
+        .iter()
+        .map(|row| format!("    {}", similarity_to_json(row)))
+        .collect();
+
+    format!(
+        "{{\n  \"part1\": {part_1},\n  \"part2\": {part_2},\n  \"pairs\": [\n{}\n  ],\n  \"similarity\": [\n{}\n  ]\n}}\n",
+        pairs.join(",\n"),
+        similarity.join(",\n")
+    )
+}
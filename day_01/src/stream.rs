@@ -0,0 +1,364 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::env;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufRead, BufReader, BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use common::{Error, Line, ParseError};
+
+use crate::reader::RowReader;
+use crate::{Metric, Weight};
+
+/// how many values each list keeps in memory before spilling a sorted run to
+/// disk, 8 MiB a list
+pub const DEFAULT_CHUNK_LEN: usize = 1 << 20;
+/// how many runs are merged at once by default, which is also how many run
+/// files each list has open at a time
+pub const DEFAULT_FAN_IN: usize = 64;
+
+/// Both answers worked out in one pass over the input
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Streamed {
+    pub distance: u128,
+    pub similarity: i128,
+    /// pairs read, not counting rejected lines
+    pub pairs: usize,
+}
+
+/// opens `path` and streams it through `stream_lists`
+pub fn stream_file(
+    path: &Path,
+    chunk_len: usize,
+    metric: Metric,
+    weight: Weight,
+    on_reject: impl FnMut(ParseError) -> Result<(), ParseError>,
+) -> Result<Streamed, Error> {
+    let file = File::open(path).map_err(|source| Error::Io {
+        path: path.to_path_buf(),
+        source,
+    })?;
+
+    stream_lists(
+        BufReader::new(file),
+        Some(path),
+        chunk_len,
+        metric,
+        weight,
+        on_reject,
+    )
+}
+
+/// reads the two lists a line at a time and answers both parts without
+/// holding the input in memory
+///
+/// each list goes through an `ExternalSorter` that keeps at most `chunk_len`
+/// values in memory. part 1 walks the two sorted streams side by side. part 2
+/// walks them again as a merge join, counting each left value's matches as
+/// the right stream catches up, so it needs no maps. pairs are scored with
+/// `metric` and matches with `weight`, as in `total_distance_with` and
+/// `similarity_score_with`
+///
+/// lines are read like `read_lists`. every rejected line is passed to
+/// `on_reject`, which returns the error to stop there or Ok to skip the line.
+/// errors name `path` when there is one
+pub fn stream_lists<R: BufRead>(
+    mut input: R,
+    path: Option<&Path>,
+    chunk_len: usize,
+    metric: Metric,
+    weight: Weight,
+    mut on_reject: impl FnMut(ParseError) -> Result<(), ParseError>,
+) -> Result<Streamed, Error> {
+    let io_error = |source| Error::Io {
+        path: path.map_or_else(|| PathBuf::from("<input>"), Path::to_path_buf),
+        source,
+    };
+    let with_file = |err: ParseError| match path {
+        Some(p) => err.with_file(p),
+        None => err,
+    };
+
+    let mut rows = RowReader::new(Some(2));
+    let mut left = ExternalSorter::new(chunk_len);
+    let mut right = ExternalSorter::new(chunk_len);
+    let mut pairs = 0;
+
+    let mut buffer = String::new();
+    let mut number = 0;
+    loop {
+        buffer.clear();
+        if input.read_line(&mut buffer).map_err(io_error)? == 0 {
+            break;
+        }
+        number += 1;
+
+        let line = Line {
+            number,
+            text: buffer.trim_end_matches(['\n', '\r']),
+        };
+        match rows.read(&line) {
+            Some(Ok(row)) => {
+                left.push(row[0]).map_err(io_error)?;
+                right.push(row[1]).map_err(io_error)?;
+                pairs += 1;
+            }
+            Some(Err(err)) => on_reject(with_file(err)).map_err(Error::Parse)?,
+            None => {}
+        }
+    }
+
+    let left = left.finish().map_err(io_error)?;
+    let right = right.finish().map_err(io_error)?;
+
+    let mut distance = 0;
+    for (l, r) in left
+        .iter()
+        .map_err(io_error)?
+        .zip(right.iter().map_err(io_error)?)
+    {
+        distance += metric.distance(l.map_err(io_error)?, r.map_err(io_error)?);
+    }
+
+    let similarity = join_similarity(
+        left.iter().map_err(io_error)?,
+        right.iter().map_err(io_error)?,
+        weight,
+    )
+    .map_err(io_error)?;
+
+    Ok(Streamed {
+        distance,
+        similarity,
+        pairs,
+    })
+}
+
+/// Sorts more numbers than fit in memory
+///
+/// Values are buffered until there are `chunk_len` of them, then sorted and
+/// written to a temporary file as a run. `finish` merges the runs back into
+/// one ascending stream, at most `fan_in` runs at a time so the number of
+/// open files stays bounded however big the input is. Nothing touches the
+/// disk when everything fits in a single chunk.
+pub struct ExternalSorter {
+    chunk_len: usize,
+    fan_in: usize,
+    buffer: Vec<i64>,
+    runs: Vec<Run>,
+}
+
+impl ExternalSorter {
+    pub fn new(chunk_len: usize) -> Self {
+        ExternalSorter::with_fan_in(chunk_len, DEFAULT_FAN_IN)
+    }
+    /// like `new` but merging `fan_in` runs at a time, at least 2
+    pub fn with_fan_in(chunk_len: usize, fan_in: usize) -> Self {
+        let chunk_len = chunk_len.max(1);
+        ExternalSorter {
+            chunk_len,
+            fan_in: fan_in.max(2),
+            buffer: Vec::with_capacity(chunk_len.min(DEFAULT_CHUNK_LEN)),
+            runs: Vec::new(),
+        }
+    }
+    pub fn push(&mut self, value: i64) -> io::Result<()> {
+        self.buffer.push(value);
+        if self.buffer.len() >= self.chunk_len {
+            self.spill()?;
+        }
+        Ok(())
+    }
+    /// how many runs have been written to disk so far
+    pub fn runs(&self) -> usize {
+        self.runs.len()
+    }
+    fn spill(&mut self) -> io::Result<()> {
+        self.buffer.sort_unstable();
+        self.runs
+            .push(Run::write(self.buffer.iter().copied().map(Ok))?);
+        self.buffer.clear();
+        Ok(())
+    }
+    /// every value pushed, sorted and ready to be read in order
+    pub fn finish(mut self) -> io::Result<SortedList> {
+        if self.runs.is_empty() {
+            self.buffer.sort_unstable();
+            return Ok(SortedList::Memory(self.buffer));
+        }
+        if !self.buffer.is_empty() {
+            self.spill()?;
+        }
+
+        // merge the oldest runs into one until few enough are left to merge
+        // in a single pass
+        while self.runs.len() > self.fan_in {
+            let runs: Vec<Run> = self.runs.drain(..self.fan_in).collect();
+            let merged = Run::write(merge(&runs)?)?;
+            self.runs.push(merged);
+        }
+
+        Ok(SortedList::Runs(self.runs))
+    }
+}
+
+/// The output of `ExternalSorter::finish`, which can be read through as
+/// many times as needed
+pub enum SortedList {
+    Memory(Vec<i64>),
+    /// no more than the sorter's fan-in of sorted runs, removed again when
+    /// the list is dropped
+    Runs(Vec<Run>),
+}
+
+impl SortedList {
+    /// the values smallest first
+    pub fn iter(&self) -> io::Result<Sorted<'_>> {
+        match self {
+            SortedList::Memory(values) => Ok(Sorted::Memory(values.iter())),
+            SortedList::Runs(runs) => merge(runs),
+        }
+    }
+}
+
+/// merges sorted `runs` into one ascending stream, opening every run
+fn merge(runs: &[Run]) -> io::Result<Sorted<'_>> {
+    let mut readers = Vec::with_capacity(runs.len());
+    let mut heap = BinaryHeap::with_capacity(runs.len());
+    for (i, run) in runs.iter().enumerate() {
+        let mut reader = BufReader::new(File::open(&run.path)?);
+        if let Some(value) = read_value(&mut reader)? {
+            heap.push(Reverse((value, i)));
+        }
+        readers.push(reader);
+    }
+
+    Ok(Sorted::Merge {
+        readers,
+        heap,
+        _runs: runs,
+    })
+}
+
+/// One read through a `SortedList`
+pub enum Sorted<'a> {
+    Memory(std::slice::Iter<'a, i64>),
+    /// a k-way merge that always takes the smallest head of the runs
+    Merge {
+        readers: Vec<BufReader<File>>,
+        heap: BinaryHeap<Reverse<(i64, usize)>>,
+        /// keeps the run files around until the merge is dropped
+        _runs: &'a [Run],
+    },
+}
+
+impl Iterator for Sorted<'_> {
+    type Item = io::Result<i64>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            Sorted::Memory(values) => values.next().copied().map(Ok),
+            Sorted::Merge { readers, heap, .. } => {
+                let Reverse((value, i)) = heap.pop()?;
+                match read_value(&mut readers[i]) {
+                    Ok(Some(next)) => heap.push(Reverse((next, i))),
+                    Ok(None) => {}
+                    Err(err) => return Some(Err(err)),
+                }
+                Some(Ok(value))
+            }
+        }
+    }
+}
+
+/// `similarity_score_with` over two ascending streams
+///
+/// each distinct left value moves the right stream up to it, counting the
+/// right values equal to it on the way, so only the current values are held.
+/// a repeated left value scores the same again
+fn join_similarity(
+    left: impl Iterator<Item = io::Result<i64>>,
+    right: impl Iterator<Item = io::Result<i64>>,
+    weight: Weight,
+) -> io::Result<i128> {
+    let mut right = right.peekable();
+    let mut previous: Option<(i64, i128)> = None;
+    let mut similarity = 0;
+
+    for l in left {
+        let l = l?;
+        if let Some((value, score)) = previous {
+            if value == l {
+                similarity += score;
+                continue;
+            }
+        }
+
+        let mut count = 0;
+        while let Some(r) = right.next_if(|r| r.as_ref().map_or(true, |&r| r <= l)) {
+            if r? == l {
+                count += 1;
+            }
+        }
+        let score = match count {
+            0 => 0,
+            n => weight.weight(l, n),
+        };
+        similarity += score;
+        previous = Some((l, score));
+    }
+
+    Ok(similarity)
+}
+
+/// A sorted run in a temporary file, removed again when dropped
+pub struct Run {
+    path: PathBuf,
+}
+
+/// numbers the run files so each one gets its own name
+static NEXT_RUN: AtomicUsize = AtomicUsize::new(0);
+
+impl Run {
+    /// writes `values` as little endian i64s
+    fn write(values: impl IntoIterator<Item = io::Result<i64>>) -> io::Result<Run> {
+        let name = format!(
+            "day_01-{}-{}.run",
+            process::id(),
+            NEXT_RUN.fetch_add(1, Ordering::Relaxed)
+        );
+        let path = env::temp_dir().join(name);
+
+        // only a file this created is removed again on drop
+        let file = OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&path)?;
+        let run = Run { path };
+        let mut out = BufWriter::new(file);
+        for value in values {
+            out.write_all(&value?.to_le_bytes())?;
+        }
+        out.flush()?;
+
+        Ok(run)
+    }
+}
+
+impl Drop for Run {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+/// the next value in a run, None at the end
+fn read_value(reader: &mut impl Read) -> io::Result<Option<i64>> {
+    let mut bytes = [0; 8];
+    match reader.read_exact(&mut bytes) {
+        Ok(()) => Ok(Some(i64::from_le_bytes(bytes))),
+        Err(err) if err.kind() == io::ErrorKind::UnexpectedEof => Ok(None),
+        Err(err) => Err(err),
+    }
+}
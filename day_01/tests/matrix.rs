@@ -0,0 +1,55 @@
+use day_01::matrix::{csv_header, distance_matrix, format_csv, format_table, similarity_matrix};
+use day_01::{read_columns, similarity_score, total_distance, Metric, Weight};
+
+const INPUT: &str = include_str!("../input.csv");
+const THREE_COLUMNS: &str = "a,b,c\n1,3,2\n2,3,5\n3,1,2\n";
+
+#[test]
+fn three_column_matrices() {
+    let table = read_columns(THREE_COLUMNS, None);
+    assert_eq!(table.names(), ["a", "b", "c"]);
+
+    let distance = distance_matrix(&table.columns, Metric::Absolute);
+    assert_eq!(distance, [[0, 1, 3], [1, 0, 4], [3, 4, 0]]);
+
+    // row a column b looks the a values up in b, row b column a the other
+    // way round. with the puzzle's weight both come to the same
+    let similarity = similarity_matrix(&table.columns, Weight::Product);
+    assert_eq!(similarity, [[6, 7, 4], [7, 13, 0], [4, 0, 13]]);
+
+    // but scoring each match once per left value, they don't
+    let presence = similarity_matrix(&table.columns, Weight::Presence);
+    assert_eq!(presence, [[6, 4, 2], [7, 7, 0], [4, 0, 9]]);
+}
+
+#[test]
+fn three_column_output() {
+    let table = read_columns(THREE_COLUMNS, None);
+    let names = table.names();
+    let similarity = similarity_matrix(&table.columns, Weight::Presence);
+
+    assert_eq!(
+        format_table("part 2 similarity", &names, &similarity),
+        "part 2 similarity\n   a  b  c\na  6  4  2\nb  7  7  0\nc  4  0  9\n"
+    );
+    assert_eq!(csv_header(&names), "metric,column,a,b,c\n");
+    assert_eq!(
+        format_csv("similarity", &names, &similarity),
+        "similarity,a,6,4,2\nsimilarity,b,7,7,0\nsimilarity,c,4,0,9\n"
+    );
+}
+
+/// with two columns the off-diagonal entries are the puzzle answers
+#[test]
+fn two_columns_give_the_answers() {
+    let table = read_columns(INPUT, None);
+    let (l, r) = (&table.columns[0], &table.columns[1]);
+
+    let distance = distance_matrix(&table.columns, Metric::Absolute);
+    let similarity = similarity_matrix(&table.columns, Weight::Product);
+
+    assert_eq!(distance[0][1], total_distance(l, r));
+    assert_eq!(distance[1][0], total_distance(l, r));
+    assert_eq!(similarity[0][1], similarity_score(l, r));
+    assert_eq!(similarity[1][0], similarity_score(r, l));
+}
@@ -0,0 +1,45 @@
+use day_01::{read_columns, read_lists};
+
+#[test]
+fn reads_one_header() {
+    let lists = read_lists("left,right\n1,2\n3,4\n");
+
+    assert_eq!(
+        lists.header,
+        Some(vec![String::from("left"), String::from("right")])
+    );
+    assert_eq!((lists.left, lists.right), (vec![1, 3], vec![2, 4]));
+    assert!(lists.rejected.is_empty());
+}
+
+/// only the first text row is a header, later ones are bad rows and must
+/// show up as rejected rather than vanish
+#[test]
+fn rejects_extra_header_rows() {
+    let lists = read_lists("left,right\njunk\n\nmore,junk\n1,2\n");
+
+    assert_eq!(
+        lists.header,
+        Some(vec![String::from("left"), String::from("right")])
+    );
+    assert_eq!(lists.left, [1]);
+
+    let rejected: Vec<(usize, &str)> = lists
+        .rejected
+        .iter()
+        .map(|err| (err.line(), err.message()))
+        .collect();
+    assert_eq!(
+        rejected,
+        [(2, "expected 2 columns, found 1"), (4, "expected a number")]
+    );
+}
+
+#[test]
+fn header_sets_the_width() {
+    let table = read_columns("a b c\nx y z\n1 2 3\n", None);
+
+    assert_eq!(table.columns, [[1], [2], [3]]);
+    assert_eq!(table.rejected.len(), 1);
+    assert_eq!(table.rejected[0].line(), 2);
+}
@@ -0,0 +1,48 @@
+use day_01::report::{pair_rows, report_to_json, similarity_rows};
+use day_01::{parse_lists, similarity_score_with, total_distance_with, Metric, Weight};
+
+const INPUT: &str = include_str!("../input.csv");
+
+/// the last running total and the summed contributions are the answers
+#[test]
+fn report_adds_up_to_the_answers() {
+    let (l, r) = parse_lists(INPUT).unwrap();
+
+    for metric in [Metric::Absolute, Metric::Squared, Metric::Capped(1000)] {
+        let pairs = pair_rows(&l, &r, metric);
+        assert_eq!(pairs.len(), l.len());
+        assert_eq!(
+            pairs.last().unwrap().total,
+            total_distance_with(&l, &r, metric)
+        );
+    }
+
+    for weight in [
+        Weight::Product,
+        Weight::Count,
+        Weight::Presence,
+        Weight::Squared,
+    ] {
+        let rows = similarity_rows(&l, &r, weight);
+        let total: i128 = rows.iter().map(|row| row.contribution).sum();
+        assert_eq!(total, similarity_score_with(&l, &r, weight));
+        assert_eq!(
+            rows.iter().map(|row| row.left_count).sum::<usize>(),
+            l.len()
+        );
+    }
+}
+
+#[test]
+fn report_json_has_both_answers() {
+    let (l, r) = parse_lists("3 4\n4 3\n2 5\n1 3\n3 9\n3 3\n").unwrap();
+    let json = report_to_json(
+        &pair_rows(&l, &r, Metric::Absolute),
+        &similarity_rows(&l, &r, Weight::Product),
+    );
+
+    assert!(json.starts_with("{\n  \"part1\": 11,\n  \"part2\": 31,\n"));
+    assert!(json.contains("{\"index\": 5, \"left\": 4, \"right\": 9, \"diff\": 5, \"total\": 11}"));
+    assert!(json
+        .contains("{\"value\": 3, \"left_count\": 3, \"right_count\": 3, \"contribution\": 27}"));
+}
@@ -0,0 +1,127 @@
+use common::Solution;
+use day_01::stream::{stream_lists, ExternalSorter};
+use day_01::{
+    read_lists, similarity_score_with, total_distance_with, Day01, Metric, Weight, EXAMPLE,
+};
+
+const INPUT: &str = include_str!("../input.csv");
+const FORMATS: &str = include_str!("../test_formats.csv");
+
+/// streaming has to give the same answers however small the chunks are
+#[test]
+fn stream_matches_in_memory() {
+    for input in [EXAMPLE, INPUT, FORMATS] {
+        let lists = Day01::parse(input).unwrap();
+        let expected = (Day01::part1(&lists), Day01::part2(&lists));
+
+        for chunk_len in [1, 7, 1000, 1 << 20] {
+            let streamed = stream_lists(
+                input.as_bytes(),
+                None,
+                chunk_len,
+                Metric::Absolute,
+                Weight::Product,
+                Err,
+            )
+            .unwrap();
+            assert_eq!((streamed.distance, streamed.similarity), expected);
+            assert_eq!(streamed.pairs, lists.0.len());
+        }
+    }
+}
+
+#[test]
+fn stream_reports_the_same_rejected_lines() {
+    let input = "left right\n3 4\n5,,6\nabc\n4 x\n2 5\n";
+    let mut rejected = Vec::new();
+
+    let streamed = stream_lists(
+        input.as_bytes(),
+        None,
+        1,
+        Metric::Absolute,
+        Weight::Product,
+        |err| {
+            rejected.push(err);
+            Ok(())
+        },
+    )
+    .unwrap();
+
+    assert_eq!(rejected, read_lists(input).rejected);
+    assert_eq!(streamed.pairs, 2);
+    assert!(stream_lists(
+        input.as_bytes(),
+        None,
+        1,
+        Metric::Absolute,
+        Weight::Product,
+        Err
+    )
+    .is_err());
+}
+
+#[test]
+fn external_sort_merges_runs() {
+    let values: Vec<i64> = (0..5000).map(|i| (i * 7919) % 5003 - 2500).collect();
+    let mut sorter = ExternalSorter::new(64);
+    for &value in &values {
+        sorter.push(value).unwrap();
+    }
+    assert!(sorter.runs() > 1);
+
+    let sorted: Vec<i64> = sorter
+        .finish()
+        .unwrap()
+        .iter()
+        .unwrap()
+        .map(Result::unwrap)
+        .collect();
+    let mut expected = values;
+    expected.sort();
+    assert_eq!(sorted, expected);
+}
+
+/// with a small fan-in the runs are merged over several passes, which is
+/// what keeps the open files bounded on huge inputs
+#[test]
+fn external_sort_merges_in_passes() {
+    let values: Vec<i64> = (0..5000).map(|i| (i * 7919) % 5003 - 2500).collect();
+    let mut sorter = ExternalSorter::with_fan_in(4, 3);
+    for &value in &values {
+        sorter.push(value).unwrap();
+    }
+    assert_eq!(sorter.runs(), 1250);
+
+    let sorted: Vec<i64> = sorter
+        .finish()
+        .unwrap()
+        .iter()
+        .unwrap()
+        .map(Result::unwrap)
+        .collect();
+    let mut expected = values;
+    expected.sort();
+    assert_eq!(sorted, expected);
+}
+
+#[test]
+fn stream_matches_other_metrics_and_weights() {
+    let input = "-3 4\n4 -3\n-2 5\n1 -3\n3 -9\n-3 3\n";
+    let (l, r) = Day01::parse(input).unwrap();
+
+    for metric in [Metric::Absolute, Metric::Squared, Metric::Capped(2)] {
+        for weight in [
+            Weight::Product,
+            Weight::Count,
+            Weight::Presence,
+            Weight::Squared,
+        ] {
+            let streamed = stream_lists(input.as_bytes(), None, 2, metric, weight, Err).unwrap();
+            assert_eq!(streamed.distance, total_distance_with(&l, &r, metric));
+            assert_eq!(streamed.similarity, similarity_score_with(&l, &r, weight));
+        }
+    }
+    assert_eq!(Day01::part1(&(l.clone(), r.clone())), 11);
+    assert_eq!(Day01::part2(&(l, r)), -5);
+}
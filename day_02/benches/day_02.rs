@@ -0,0 +1,51 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+use day_02::{brute_force_problem_dampener, is_safe_report, parse_reports};
+
+const INPUT: &str = include_str!("../input.csv");
+
+/// an ascending report of `len` levels with one bad jump in the middle, so
+/// the dampener has to try most removals before finding the fix
+fn synthetic_report(len: usize) -> Vec<usize> {
+    let mut report: Vec<usize> = (0..len).map(|i| i * 2).collect();
+    report[len / 2] += 10;
+    report
+}
+
+fn bench_day_02(c: &mut Criterion) {
+    let reports = parse_reports(INPUT).unwrap();
+
+    c.bench_function("day_02/is_safe_report/input", |b| {
+        b.iter(|| {
+            reports
+                .iter()
+                .filter(|r| is_safe_report(black_box(r)))
+                .count()
+        })
+    });
+    c.bench_function("day_02/brute_force_problem_dampener/input", |b| {
+        b.iter(|| {
+            reports
+                .iter()
+                .filter(|r| brute_force_problem_dampener(black_box(r)))
+                .count()
+        })
+    });
+
+    let mut group = c.benchmark_group("day_02/synthetic");
+    for len in [8, 64, 512] {
+        let report = synthetic_report(len);
+        group.bench_with_input(BenchmarkId::new("is_safe_report", len), &len, |b, _| {
+            b.iter(|| is_safe_report(black_box(&report)))
+        });
+        group.bench_with_input(
+            BenchmarkId::new("brute_force_problem_dampener", len),
+            &len,
+            |b, _| b.iter(|| brute_force_problem_dampener(black_box(&report))),
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_day_02);
+criterion_main!(benches);
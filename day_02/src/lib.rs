@@ -0,0 +1,142 @@
+use common::{ParseError, Solution};
+
+/// smallest allowed difference between adjacent levels
+pub const MIN_DIFF: usize = 1;
+/// largest allowed difference between adjacent levels
+pub const MAX_DIFF: usize = 3;
+
+/// the generated fallback reports used when no input file is given
+pub const EXAMPLE: &str = include_str!("../test.csv");
+
+// answer_1 solution algo
+// determine that the report has at least 2 values
+// determine asc/desc order based on first 2 items
+// begin iteration on index 2 and look backwards to
+// - ensure order matches initial order
+// - ensure the diff between current and previous are within min/max tolerance
+// if the report is good, increment good reports count
+//
+// answer_2 solution algo
+// when a bad report is encountered, considering the current index of the
+// for loop, remove the prev index from the array and retest and if that
+// is also bad remove the next index from the original array and retest
+// if both retests are bad then the Problem Dampener did not help
+//
+// OR
+//
+// when a bad report is encountered, brute force the Problem Dampener
+// by generating lists with one element removed and testing the
+// revised list
+/// Day 2: Red-Nosed Reports
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<Vec<usize>>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_reports(input)
+    }
+
+    fn part1(data: &Self::Input) -> usize {
+        data.iter().filter(|report| is_safe_report(report)).count()
+    }
+
+    fn part2(data: &Self::Input) -> usize {
+        let mut good_report_count: usize = 0;
+        let mut damp_report_count: usize = 0;
+
+        for report in data.iter() {
+            if is_safe_report(report) {
+                good_report_count += 1;
+            } else if brute_force_problem_dampener(report) {
+                damp_report_count += 1;
+            }
+        }
+
+        damp_report_count + good_report_count
+    }
+}
+
+/// a report is safe when its levels are strictly ascending or descending and
+/// adjacent levels differ by `MIN_DIFF..=MAX_DIFF`
+pub fn is_safe_report(report: &[usize]) -> bool {
+    if report.len() < 2 {
+        return false;
+    }
+
+    let mut prev = report.first().unwrap();
+    let mut next = report.get(1).unwrap();
+
+    if prev == next {
+        return false;
+    }
+
+    let is_ascending = is_ascending_order(prev, next);
+
+    for i in 1..report.len() {
+        prev = report.get(i - 1).unwrap();
+        next = report.get(i).unwrap();
+        if is_ascending_order(prev, next) != is_ascending {
+            return false;
+        }
+        if !is_within_tolerance(prev, next) {
+            return false;
+        }
+    }
+
+    true
+}
+
+/// returns true when removing any single level makes the report safe
+// there's definitely a better way
+pub fn brute_force_problem_dampener(report: &[usize]) -> bool {
+    let mut tmp: Vec<usize> = Vec::new();
+    for i in 0..report.len() {
+        tmp.clear();
+        for j in 0..report.len() {
+            if j != i {
+                tmp.push(*report.get(j).unwrap());
+            }
+        }
+
+        if is_safe_report(&tmp) {
+            return true;
+        }
+    }
+
+    false
+}
+
+/// true when the difference between two adjacent levels is within tolerance
+pub fn is_within_tolerance(prev: &usize, next: &usize) -> bool {
+    let diff = prev.abs_diff(*next);
+
+    (MIN_DIFF..=MAX_DIFF).contains(&diff)
+}
+
+/// true when the levels are increasing
+pub fn is_ascending_order(prev: &usize, next: &usize) -> bool {
+    prev < next
+}
+
+/// parses one report per line, levels separated by whitespace. blank lines
+/// are skipped
+pub fn parse_reports(input: &str) -> Result<Vec<Vec<usize>>, ParseError> {
+    let mut data: Vec<Vec<usize>> = Vec::new();
+
+    for line in common::lines(input) {
+        if line.text.trim().is_empty() {
+            continue;
+        }
+        let split: Vec<&str> = line.text.split_whitespace().collect();
+        let mut report: Vec<usize> = Vec::new();
+        for v in split {
+            report.push(line.parse(v)?);
+        }
+        data.push(report);
+    }
+
+    Ok(data)
+}
@@ -0,0 +1,16 @@
+use day_02::parse_reports;
+
+#[test]
+fn blank_lines_and_extra_spaces_are_skipped() {
+    let reports = parse_reports("7 6 4 2 1 \n\n1  2 7 8 9\n\n").unwrap();
+
+    assert_eq!(reports, [vec![7, 6, 4, 2, 1], vec![1, 2, 7, 8, 9]]);
+}
+
+#[test]
+fn bad_level_is_still_an_error() {
+    let err = parse_reports("7 6 4\n1 x 3\n").err().unwrap();
+
+    assert_eq!((err.line(), err.column()), (2, 3));
+    assert_eq!(err.token(), "x");
+}
@@ -0,0 +1,28 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+
+use day_03::{scan, try_mul};
+
+const INPUT: &str = include_str!("../input.txt");
+
+fn bench_day_03(c: &mut Criterion) {
+    c.bench_function("day_03/scan/input", |b| b.iter(|| scan(black_box(INPUT))));
+    c.bench_function("day_03/try_mul/valid", |b| {
+        b.iter(|| try_mul(black_box("mul(123,456)xyz")))
+    });
+    c.bench_function("day_03/try_mul/invalid", |b| {
+        b.iter(|| try_mul(black_box("mul(123,45!)xyz")))
+    });
+
+    let mut group = c.benchmark_group("day_03/synthetic");
+    for copies in [1, 10, 100] {
+        let memory = INPUT.repeat(copies);
+        group.throughput(Throughput::Bytes(memory.len() as u64));
+        group.bench_with_input(BenchmarkId::new("scan", copies), &copies, |b, _| {
+            b.iter(|| scan(black_box(&memory)))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_day_03);
+criterion_main!(benches);
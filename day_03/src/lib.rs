@@ -0,0 +1,111 @@
+use common::{ParseError, Solution};
+
+/// the fallback memory used when no input file is given
+pub const EXAMPLE: &str = include_str!("../test.txt");
+
+/// Day 3: Mull It Over
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = String;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(String::from(input))
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        scan(input).0
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        scan(input).1
+    }
+}
+
+/// scans the corrupted memory for mul(a,b) instructions
+///
+/// Returns (sum of all mul products, sum of mul products while enabled by do()/don't())
+pub fn scan(input: &str) -> (usize, usize) {
+    if input.is_empty() {
+        return (0, 0);
+    }
+
+    let mut slice: &str;
+    let mut cursor: usize = 0;
+    let mut answer_1: usize = 0;
+    let mut answer_2: usize = 0;
+    let mut is_mul_enabled = true;
+
+    loop {
+        slice = &input[cursor..];
+        if slice.starts_with("m") {
+            let (v, cur) = try_mul(slice);
+            if let Some(result) = v {
+                answer_1 += result;
+                if is_mul_enabled {
+                    answer_2 += result;
+                }
+            }
+
+            cursor += cur;
+        } else if slice.starts_with("d") {
+            let (v, cur) = try_do_dont(slice);
+            if let Some(result) = v {
+                is_mul_enabled = result;
+            }
+
+            cursor += cur;
+        } else {
+            // step over a whole character, the memory isn't only ascii
+            cursor += slice.chars().next().map_or(1, char::len_utf8);
+        }
+
+        if cursor >= input.len() {
+            break;
+        }
+    }
+
+    (answer_1, answer_2)
+}
+
+/// Returns (Some(product), cursor_offset) when mul(a, b) found
+/// Returns (None, 1) when mul(a, b) not found
+pub fn try_mul(slice: &str) -> (Option<usize>, usize) {
+    if !slice.starts_with("mul(") {
+        return (None, 1);
+    }
+
+    if let Some(closing_index) = slice.find(")") {
+        let segment = &slice[4..closing_index];
+        let parts: Vec<&str> = segment.split(",").collect();
+
+        if parts.len() != 2 {
+            return (None, 1);
+        }
+
+        if let Ok(a) = parts.first().unwrap().parse::<usize>() {
+            if let Ok(b) = parts.get(1).unwrap().parse::<usize>() {
+                return (Some(a * b), closing_index);
+            }
+        }
+    }
+
+    (None, 1)
+}
+
+/// Returns (Some(true), cursor_offset) if do() is found
+/// Returns (Some(false), cursor_offset) if don't() is found
+/// Returns (None, 1) otherwise
+pub fn try_do_dont(slice: &str) -> (Option<bool>, usize) {
+    if slice.starts_with("do()") {
+        return (Some(true), 4);
+    }
+
+    if slice.starts_with("don't()") {
+        return (Some(false), 7);
+    }
+
+    (None, 1)
+}
@@ -0,0 +1,8 @@
+use day_03::scan;
+
+/// slicing at the byte after a multi-byte character used to panic
+#[test]
+fn non_ascii_memory() {
+    assert_eq!(scan("mul(2,3)é mul(4,5)\n"), (26, 26));
+    assert_eq!(scan("don't()€mul(4,5)ümul(1,1)do()😀mul(3,3)"), (30, 9));
+}
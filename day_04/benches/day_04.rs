@@ -0,0 +1,65 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+use common::Solution;
+use day_04::{count_mas_crossings, count_xmas, Board, Day04};
+use grid::{Direction, Point};
+
+const INPUT: &str = include_str!("../input.txt");
+
+/// the input repeated `n` times across and `n` times down
+fn tiled(input: &str, n: usize) -> String {
+    let mut result = String::new();
+    for _ in 0..n {
+        for line in input.lines() {
+            result.push_str(&line.repeat(n));
+            result.push('\n');
+        }
+    }
+    result
+}
+
+/// reads every sequence of `steps` letters in every direction
+fn sweep(board: &Board, steps: usize) -> usize {
+    let mut total = 0;
+    for pos in board.positions() {
+        for dir in Direction::ALL {
+            if board.get_sequence(pos, dir, steps).is_some() {
+                total += 1;
+            }
+        }
+    }
+    total
+}
+
+fn bench_day_04(c: &mut Criterion) {
+    let board = Day04::parse(INPUT).unwrap();
+
+    c.bench_function("day_04/get_sequence/single", |b| {
+        b.iter(|| board.get_sequence(black_box(Point::new(3, 3)), Direction::SE, 4))
+    });
+    c.bench_function("day_04/get_sequence/sweep", |b| {
+        b.iter(|| sweep(black_box(&board), 4))
+    });
+    c.bench_function("day_04/count_xmas/input", |b| {
+        b.iter(|| count_xmas(black_box(&board)))
+    });
+    c.bench_function("day_04/count_mas_crossings/input", |b| {
+        b.iter(|| count_mas_crossings(black_box(&board)))
+    });
+
+    let mut group = c.benchmark_group("day_04/synthetic");
+    group.sample_size(20);
+    for n in [1, 2, 4] {
+        let board = Day04::parse(&tiled(INPUT, n)).unwrap();
+        group.bench_with_input(BenchmarkId::new("count_xmas", n), &n, |b, _| {
+            b.iter(|| count_xmas(black_box(&board)))
+        });
+        group.bench_with_input(BenchmarkId::new("count_mas_crossings", n), &n, |b, _| {
+            b.iter(|| count_mas_crossings(black_box(&board)))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_day_04);
+criterion_main!(benches);
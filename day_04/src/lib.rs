@@ -0,0 +1,106 @@
+use std::collections::HashSet;
+
+use common::{ParseError, Solution};
+use grid::{Direction, Grid, Point};
+
+/// the small word search used when no input file is given
+pub const EXAMPLE: &str = include_str!("../test.txt");
+
+/// the word search, one letter per cell
+pub struct Board {
+    grid: Grid<char>,
+}
+
+impl Board {
+    pub fn new(grid: Grid<char>) -> Self {
+        Board { grid }
+    }
+    /// reads `steps` letters starting at `pos` and moving in `dir`
+    /// returns None when the walk leaves the board before reading them all
+    pub fn get_sequence(&self, pos: Point, dir: Direction, steps: usize) -> Option<String> {
+        let result: String = self
+            .grid
+            .ray(pos, dir)
+            .take(steps)
+            .map(|(_, ch)| *ch)
+            .collect();
+
+        if result.chars().count() < steps {
+            return None;
+        }
+
+        Some(result)
+    }
+    pub fn row_count(&self) -> usize {
+        self.grid.height()
+    }
+    pub fn col_count(&self) -> usize {
+        self.grid.width()
+    }
+    /// every cell on the board, row by row
+    pub fn positions(&self) -> impl Iterator<Item = Point> + '_ {
+        self.grid.points()
+    }
+}
+
+/// Day 4: Ceres Search
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Board;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(Board::new(Grid::parse(input)?))
+    }
+
+    fn part1(board: &Self::Input) -> usize {
+        count_xmas(board)
+    }
+
+    fn part2(board: &Self::Input) -> usize {
+        count_mas_crossings(board)
+    }
+}
+
+/// counts every occurrence of XMAS in any of the eight directions
+pub fn count_xmas(board: &Board) -> usize {
+    let mut total_xmas: usize = 0;
+
+    for pos in board.positions() {
+        for dir in Direction::ALL {
+            if let Some(seq) = board.get_sequence(pos, dir, 4) {
+                if seq.eq("XMAS") {
+                    total_xmas += 1;
+                }
+            }
+        }
+    }
+
+    total_xmas
+}
+
+/// counts the places where two diagonal MAS words cross on their A
+pub fn count_mas_crossings(board: &Board) -> usize {
+    let mut total_mas_crossings: usize = 0;
+    let mut mas_crossing_set = HashSet::new();
+
+    for pos in board.positions() {
+        for dir in Direction::ORDINAL {
+            if let Some(seq) = board.get_sequence(pos, dir, 3) {
+                if seq.eq("MAS") {
+                    // the A in the middle of the MAS is where the crossing happens
+                    let key = pos.step(dir);
+                    if !mas_crossing_set.contains(&key) {
+                        mas_crossing_set.insert(key);
+                    } else {
+                        total_mas_crossings += 1;
+                    }
+                }
+            }
+        }
+    }
+
+    total_mas_crossings
+}
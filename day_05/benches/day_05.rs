@@ -0,0 +1,55 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+use common::Solution;
+use day_05::{check_update_list, create_ordering_rule_key, create_ordinal_list, Day05};
+
+const INPUT: &str = include_str!("../input.txt");
+
+fn bench_day_05(c: &mut Criterion) {
+    let queue = Day05::parse(INPUT).unwrap();
+    let unordered: Vec<&Vec<usize>> = queue
+        .page_updates
+        .iter()
+        .filter(|list| check_update_list(&queue.ordering_rule_key, list).is_some())
+        .collect();
+
+    c.bench_function("day_05/check_update_list/input", |b| {
+        b.iter(|| {
+            queue
+                .page_updates
+                .iter()
+                .filter(|list| {
+                    check_update_list(&queue.ordering_rule_key, black_box(list)).is_some()
+                })
+                .count()
+        })
+    });
+    c.bench_function("day_05/create_ordinal_list/input", |b| {
+        b.iter(|| {
+            unordered
+                .iter()
+                .map(|list| create_ordinal_list(&queue.ordering_rule_key, black_box(list)))
+                .collect::<Vec<_>>()
+        })
+    });
+
+    // a total order over `pages` pages, every pair has a rule like the input
+    let mut group = c.benchmark_group("day_05/synthetic");
+    for pages in [25, 100, 400] {
+        let rules: Vec<(usize, usize)> = (0..pages)
+            .flat_map(|l| ((l + 1)..pages).map(move |r| (l, r)))
+            .collect();
+        let key = create_ordering_rule_key(&rules);
+        let reversed: Vec<usize> = (0..pages).rev().collect();
+
+        group.bench_with_input(
+            BenchmarkId::new("create_ordinal_list", pages),
+            &pages,
+            |b, _| b.iter(|| create_ordinal_list(&key, black_box(&reversed))),
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_day_05);
+criterion_main!(benches);
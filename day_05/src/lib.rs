@@ -0,0 +1,245 @@
+use std::collections::{HashMap, HashSet};
+
+use common::{ParseError, Solution};
+
+/// the sample rules and updates used when no input file is given
+pub const EXAMPLE: &str = include_str!("../test.txt");
+
+/// maps a page to every page it has a rule with: 1 when the page must come
+/// before the other page, -1 when it must come after
+pub type OrderingRuleKey = HashMap<usize, HashMap<usize, isize>>;
+
+/// `before|after` page pairs in the order they appear in the input
+pub type OrderingRules = Vec<(usize, usize)>;
+
+/// the page ordering rules keyed by page, and the page update lists
+pub struct PrintQueue {
+    pub ordering_rule_key: OrderingRuleKey,
+    pub page_updates: Vec<Vec<usize>>,
+}
+
+// solution steps for part 1
+// build a hashmap of hashmaps for each value in the rule sets
+// e.g. 47|53 the key would create to entries
+// {47: {53: 1}, 53: {47: -1}} representing that 47 comes before
+// 53, and 53 comes after 47
+// for each page update entry, ensure that the value after it maps
+// to a +1 in the constructed order rule hashmap
+//
+// solution steps for part 2
+// based on the numbers present in the update_list we can compute
+// their ordinality by referencing the ordering_rule_key and summing
+// up all the positive and negative 1 values we find for each
+// then by storing the sum as the key and the key as the value in a
+// hashmap we can get the keys, order them, and then create a list
+// of the values ordered by iterating over the ordered keys
+/// Day 5: Print Queue
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = PrintQueue;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let (ordering_rules, page_updates) = parse_rules_and_updates(input)?;
+
+        Ok(PrintQueue {
+            ordering_rule_key: create_ordering_rule_key(&ordering_rules),
+            page_updates,
+        })
+    }
+
+    fn part1(queue: &Self::Input) -> usize {
+        valid_update_midpoint_sum(queue)
+    }
+
+    fn part2(queue: &Self::Input) -> usize {
+        fixed_update_midpoint_sum(queue)
+    }
+}
+
+/// sums the middle page of every update that is already correctly ordered
+pub fn valid_update_midpoint_sum(queue: &PrintQueue) -> usize {
+    let mut valid_update_midpoint_sum: usize = 0;
+
+    for list in queue.page_updates.iter() {
+        // an empty update has no middle page to add
+        let Some(center_index) = list.len().checked_sub(1).map(|last| last / 2) else {
+            continue;
+        };
+        if check_update_list(&queue.ordering_rule_key, list).is_none() {
+            valid_update_midpoint_sum += list.get(center_index).unwrap();
+        }
+    }
+
+    valid_update_midpoint_sum
+}
+
+/// sums the middle page of every out of order update after reordering it
+pub fn fixed_update_midpoint_sum(queue: &PrintQueue) -> usize {
+    let mut fixed_update_midpoint_sum: usize = 0;
+
+    for list in queue.page_updates.iter() {
+        // an empty update has no middle page to add
+        let Some(center_index) = list.len().checked_sub(1).map(|last| last / 2) else {
+            continue;
+        };
+        if check_update_list(&queue.ordering_rule_key, list).is_some() {
+            let fixed = create_ordinal_list(&queue.ordering_rule_key, list);
+            fixed_update_midpoint_sum += fixed.get(center_index).unwrap();
+        }
+    }
+
+    fixed_update_midpoint_sum
+}
+
+/// get the ordianlity of elements in update list relative to each other
+pub fn create_ordinal_list(key: &OrderingRuleKey, update_list: &[usize]) -> Vec<usize> {
+    let mut result: Vec<usize> = Vec::new();
+    let mut map: HashMap<isize, usize> = HashMap::new();
+
+    for i in 0..(update_list.len()) {
+        let k = update_list.get(i).unwrap();
+        let mut sum: isize = 0;
+        for j in 0..update_list.len() {
+            if j == i {
+                continue;
+            }
+            // pages without a rule between them don't move each other
+            sum += key
+                .get(k)
+                .and_then(|rules| rules.get(update_list.get(j).unwrap()))
+                .unwrap_or(&0);
+        }
+
+        map.insert(sum, *k);
+    }
+
+    let mut sorted_keys: Vec<isize> = map.clone().into_keys().collect();
+    sorted_keys.sort();
+    sorted_keys.reverse();
+
+    for k in sorted_keys {
+        result.push(*map.get(&k).unwrap());
+    }
+
+    result
+}
+
+/// returns Some(index) indicating that the value ahead of the index is out of order,
+/// None for an empty list
+pub fn check_update_list(key: &OrderingRuleKey, update_list: &[usize]) -> Option<usize> {
+    for i in 0..update_list.len().saturating_sub(1) {
+        let left = update_list.get(i).unwrap();
+        // `parse` makes sure every page has rules, a page without any
+        // can't be out of order
+        let Some(rule_key) = key.get(left) else {
+            continue;
+        };
+        for j in (i + 1)..update_list.len() {
+            let right = update_list.get(j).unwrap();
+            if let Some(v) = rule_key.get(right) {
+                if v != &1isize {
+                    return Some(i);
+                }
+            }
+        }
+    }
+    None
+}
+
+/// builds the rule key from `before|after` page pairs
+pub fn create_ordering_rule_key(ordering_rules: &[(usize, usize)]) -> OrderingRuleKey {
+    let mut result: OrderingRuleKey = HashMap::new();
+
+    for &(l, r) in ordering_rules {
+        result.entry(l).or_default();
+        result.get_mut(&l).unwrap().insert(r, 1);
+
+        result.entry(r).or_default();
+        result.get_mut(&r).unwrap().insert(l, -1);
+    }
+
+    result
+}
+
+/// parses the `before|after` rule lines and the comma separated update lines
+///
+/// a line with a single number is an update of one page. any other line
+/// that isn't blank is an error, and so is a pair of pages in an update of
+/// several pages without a rule between them, since they can't be put in
+/// order
+pub fn parse_rules_and_updates(
+    input: &str,
+) -> Result<(OrderingRules, Vec<Vec<usize>>), ParseError> {
+    let mut ordering_rules: OrderingRules = Vec::new();
+    let mut page_updates: Vec<Vec<usize>> = Vec::new();
+    let mut update_lines = Vec::new();
+
+    for line in common::lines(input) {
+        if line.text.trim().is_empty() {
+            continue;
+        }
+        if !line.text.contains("|") && !line.text.contains(",") {
+            let page = line.text.trim();
+            match page.parse() {
+                Ok(page) => {
+                    page_updates.push(vec![page]);
+                    continue;
+                }
+                Err(_) => {
+                    return Err(line.error(
+                        page,
+                        "expected a rule like 47|53 or an update like 75,47,61",
+                    ))
+                }
+            }
+        }
+        if line.text.contains("|") {
+            let parts: Vec<&str> = line.text.split("|").collect();
+            ordering_rules.push((
+                line.parse(parts.first().unwrap())?,
+                line.parse(parts.last().unwrap())?,
+            ));
+        }
+        if line.text.contains(",") {
+            let parts: Vec<&str> = line.text.split(",").collect();
+            let mut updates: Vec<usize> = Vec::new();
+            for n in parts {
+                updates.push(line.parse(n)?);
+            }
+            page_updates.push(updates);
+            update_lines.push(line);
+        }
+    }
+
+    let ruled_pairs: HashSet<(usize, usize)> = ordering_rules.iter().copied().collect();
+    let ruled_pages: HashSet<usize> = ordering_rules
+        .iter()
+        .flat_map(|&(before, after)| [before, after])
+        .collect();
+    for line in update_lines {
+        let mut seen: Vec<usize> = Vec::new();
+        for n in line.text.split(",") {
+            let page = line.parse(n)?;
+            if !ruled_pages.contains(&page) {
+                return Err(line.error(n, "no ordering rule mentions this page"));
+            }
+            let unruled = seen.iter().find(|&&other| {
+                other != page
+                    && !ruled_pairs.contains(&(other, page))
+                    && !ruled_pairs.contains(&(page, other))
+            });
+            if let Some(other) = unruled {
+                return Err(line.error(
+                    n,
+                    &format!("no ordering rule between this page and page {other}"),
+                ));
+            }
+            seen.push(page);
+        }
+    }
+
+    Ok((ordering_rules, page_updates))
+}
@@ -0,0 +1,57 @@
+use common::Solution;
+use day_05::{check_update_list, Day05};
+
+const RULES: &str = "47|53\n97|47\n97|53\n";
+
+#[test]
+fn single_page_update() {
+    let queue = Day05::parse(&format!("{RULES}\n75\n97,47,53\n")).unwrap();
+
+    assert_eq!(queue.page_updates, [vec![75], vec![97, 47, 53]]);
+    assert_eq!(Day05::part1(&queue), 75 + 47);
+}
+
+#[test]
+fn unrecognised_line_is_an_error() {
+    let err = Day05::parse(&format!("{RULES}\nnot an update\n97,47\n"))
+        .err()
+        .unwrap();
+
+    assert_eq!((err.line(), err.column()), (5, 1));
+    assert_eq!(err.token(), "not an update");
+}
+
+/// a page no rule mentions used to panic when the update was checked
+#[test]
+fn page_without_rules_is_an_error() {
+    let err = Day05::parse(&format!("{RULES}\n97,12,53\n")).err().unwrap();
+
+    assert_eq!((err.line(), err.column()), (5, 4));
+    assert_eq!(err.token(), "12");
+    assert_eq!(err.message(), "no ordering rule mentions this page");
+}
+
+/// every page has rules but 13 and 47 have none between them, which used to
+/// panic when the update was reordered
+#[test]
+fn pages_without_a_rule_between_them_are_an_error() {
+    let err = Day05::parse("47|53\n97|13\n\n13,97,47,53\n").err().unwrap();
+
+    assert_eq!((err.line(), err.column()), (4, 7));
+    assert_eq!(err.token(), "47");
+    assert_eq!(
+        err.message(),
+        "no ordering rule between this page and page 13"
+    );
+}
+
+/// the helpers are public, so an empty update can reach them without `parse`
+#[test]
+fn empty_update_is_skipped() {
+    let mut queue = Day05::parse(&format!("{RULES}\n97,47,53\n53,47\n")).unwrap();
+    queue.page_updates.push(Vec::new());
+
+    assert_eq!(check_update_list(&queue.ordering_rule_key, &[]), None);
+    assert_eq!(Day05::part1(&queue), 47);
+    assert_eq!(Day05::part2(&queue), 47);
+}
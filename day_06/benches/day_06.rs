@@ -0,0 +1,115 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+use common::Solution;
+use day_06::{
+    get_distinct_visited_positions, get_total_loop_obstruction_positions, guard_loops, Day06,
+    JumpTable, Lab,
+};
+
+const INPUT: &str = include_str!("../input.txt");
+/// a random `size` x `size` map with about 5% obstructions and the guard
+/// in the middle
+///
+/// seeds are tried in turn until one produces a map the guard walks out of,
+/// since a guard stuck in a loop would never finish the part 1 walk
+fn random_lab(size: usize) -> Lab {
+    for seed in 1u64.. {
+        let mut state = seed;
+        let mut next = || {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (state >> 33) as usize
+        };
+
+        let mut map = String::with_capacity(size * (size + 1));
+        for row in 0..size {
+            for col in 0..size {
+                if row == size / 2 && col == size / 2 {
+                    map.push('^');
+                } else if next() % 20 == 0 {
+                    map.push('#');
+                } else {
+                    map.push('.');
+                }
+            }
+            map.push('\n');
+        }
+
+        let lab = Day06::parse(&map).unwrap();
+        if !guard_loops(&lab.grid, lab.guards[0], &lab.policy, lab.edge) {
+            return lab;
+        }
+    }
+
+    unreachable!()
+}
+
+fn bench_day_06(c: &mut Criterion) {
+    let lab = Day06::parse(INPUT).unwrap();
+    let visited = get_distinct_visited_positions(&lab.grid, lab.guards[0], &lab.policy, lab.edge);
+
+    c.bench_function("day_06/guard_walk/input", |b| {
+        b.iter(|| {
+            get_distinct_visited_positions(
+                black_box(&lab.grid),
+                lab.guards[0],
+                &lab.policy,
+                lab.edge,
+            )
+        })
+    });
+
+    c.bench_function("day_06/jump_table/input", |b| {
+        b.iter(|| JumpTable::new(black_box(&lab.grid), lab.edge))
+    });
+
+    let mut group = c.benchmark_group("day_06/guard_walk/synthetic");
+    for size in [128, 256, 512] {
+        let lab = random_lab(size);
+        group.bench_with_input(BenchmarkId::from_parameter(size), &size, |b, _| {
+            b.iter(|| {
+                get_distinct_visited_positions(
+                    black_box(&lab.grid),
+                    lab.guards[0],
+                    &lab.policy,
+                    lab.edge,
+                )
+            })
+        });
+    }
+    group.finish();
+
+    let mut group = c.benchmark_group("day_06/loop_obstructions");
+    group.bench_function("input", |b| {
+        b.iter(|| {
+            get_total_loop_obstruction_positions(
+                black_box(&lab.grid),
+                &visited,
+                &lab.guards,
+                &lab.policy,
+                lab.edge,
+            )
+        })
+    });
+    for size in [64, 128, 256] {
+        let lab = random_lab(size);
+        let visited =
+            get_distinct_visited_positions(&lab.grid, lab.guards[0], &lab.policy, lab.edge);
+        group.bench_with_input(BenchmarkId::new("synthetic", size), &size, |b, _| {
+            b.iter(|| {
+                get_total_loop_obstruction_positions(
+                    black_box(&lab.grid),
+                    &visited,
+                    &lab.guards,
+                    &lab.policy,
+                    lab.edge,
+                )
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_day_06);
+criterion_main!(benches);
@@ -0,0 +1,101 @@
+use grid::{Grid, Point};
+
+use crate::{
+    get_candidate_positions, get_loop_cycles, guard_loops, EdgeMode, Guard, JumpTable, LoopChecker,
+    LoopCycle, TurnPolicy, OBSTRUCTION, OPEN,
+};
+
+/// the fewest added obstructions that trap at least one guard in a loop, in
+/// the order they were placed
+///
+/// an empty list when a guard already loops, None when no set of up to
+/// `max_obstructions` does it. iterative deepening tries every single
+/// obstruction before any pair and so on, and an obstruction only counts
+/// when it lands on the current route, anywhere else it can't change the
+/// patrol. the last obstruction of each set is checked on a jump table
+/// built for the map with the earlier ones in place
+pub fn min_trap_obstructions(
+    grid: &Grid<char>,
+    guards: &[Guard],
+    policy: &TurnPolicy,
+    edge: EdgeMode,
+    max_obstructions: usize,
+) -> Option<Vec<Point>> {
+    let mut grid = grid.clone();
+
+    (0..=max_obstructions).find_map(|depth| {
+        let mut placed = Vec::new();
+        trap(&mut grid, guards, policy, edge, depth, &mut placed).then_some(placed)
+    })
+}
+
+/// true when exactly `depth` more obstructions can trap a guard, which are
+/// then left in `placed`
+fn trap(
+    grid: &mut Grid<char>,
+    guards: &[Guard],
+    policy: &TurnPolicy,
+    edge: EdgeMode,
+    depth: usize,
+    placed: &mut Vec<Point>,
+) -> bool {
+    if depth == 0 {
+        return guards
+            .iter()
+            .any(|&guard| guard_loops(grid, guard, policy, edge));
+    }
+
+    let mut candidates: Vec<Point> = get_candidate_positions(grid, guards, policy, edge)
+        .into_iter()
+        .collect();
+    candidates.sort_by_key(|pos| (pos.y, pos.x));
+
+    if depth == 1 {
+        let table = JumpTable::new(grid, edge);
+        let mut checker = LoopChecker::new(&table, policy);
+        let found = candidates
+            .into_iter()
+            .find(|&pos| guards.iter().any(|&guard| checker.loops_with(guard, pos)));
+
+        return match found {
+            Some(pos) => {
+                placed.push(pos);
+                true
+            }
+            None => false,
+        };
+    }
+
+    for pos in candidates {
+        grid.set(pos, OBSTRUCTION);
+        placed.push(pos);
+
+        if trap(grid, guards, policy, edge, depth - 1, placed) {
+            grid.set(pos, OPEN);
+            return true;
+        }
+
+        placed.pop();
+        grid.set(pos, OPEN);
+    }
+
+    false
+}
+
+/// the `k` part 2 obstructions with the longest loop cycles, longest first
+///
+/// ties keep the obstructions in reading order
+pub fn best_loop_obstructions(
+    grid: &Grid<char>,
+    guards: &[Guard],
+    policy: &TurnPolicy,
+    edge: EdgeMode,
+    k: usize,
+) -> Vec<LoopCycle> {
+    let mut cycles = get_loop_cycles(grid, guards, policy, edge);
+
+    cycles.sort_by_key(|cycle| std::cmp::Reverse(cycle.cycle.len()));
+    cycles.truncate(k);
+
+    cycles
+}
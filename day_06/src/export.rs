@@ -0,0 +1,162 @@
+use common::json;
+use grid::{Grid, Point};
+
+use crate::{is_visitable, GuardPath, LoopCycle, PathEnd, PathStep};
+
+/// the side of one map cell in the SVG, in pixels
+const CELL: usize = 10;
+/// route colours, one per guard and reused when there are more guards
+const ROUTE_COLOURS: [&str; 4] = ["#1f77b4", "#2ca02c", "#9467bd", "#8c564b"];
+const CYCLE_COLOUR: &str = "#d62728";
+const LOOP_OBSTRUCTION_COLOUR: &str = "#ff7f0e";
+
+fn step_to_json(step: &PathStep) -> String {
+    format!(
+        "{{\"step\": {}, \"x\": {}, \"y\": {}, \"dir\": {}}}",
+        step.step,
+        step.pos.x,
+        step.pos.y,
+        json::quote(step.dir.name())
+    )
+}
+
+fn steps_to_json(steps: &[PathStep]) -> String {
+    let steps: Vec<String> = steps.iter().map(step_to_json).collect();
+
+    format!("[{}]", steps.join(", "))
+}
+
+fn point_to_json(pos: Point) -> String {
+    format!("{{\"x\": {}, \"y\": {}}}", pos.x, pos.y)
+}
+
+/// a guard's path as a JSON object with its steps and how it ended
+pub fn path_to_json(path: &GuardPath) -> String {
+    let end = match path.end {
+        PathEnd::Exited => String::from("\"end\": \"exited\""),
+        PathEnd::Looped { cycle_start } => {
+            format!("\"end\": \"looped\", \"cycle_start\": {cycle_start}")
+        }
+    };
+
+    format!("{{{end}, \"steps\": {}}}", steps_to_json(&path.steps))
+}
+
+/// a part 2 obstruction and its cycle as a JSON object, with guards counted
+/// from 1 like `--guard`
+pub fn cycle_to_json(cycle: &LoopCycle) -> String {
+    format!(
+        "{{\"obstruction\": {}, \"guard\": {}, \"cycle\": {}}}",
+        point_to_json(cycle.obstruction),
+        cycle.guard + 1,
+        steps_to_json(&cycle.cycle)
+    )
+}
+
+/// the map size, every guard's path and the loop cycles as one JSON document
+pub fn report_to_json(grid: &Grid<char>, paths: &[GuardPath], cycles: &[LoopCycle]) -> String {
+    let paths: Vec<String> = paths
+        .iter()
+        .map(|p| format!("    {}", path_to_json(p)))
+        .collect();
+    let cycles: Vec<String> = cycles
+        .iter()
+        .map(|c| format!("    {}", cycle_to_json(c)))
+        .collect();
+
+    format!(
+        "{{\n  \"width\": {},\n  \"height\": {},\n  \"guards\": [\n{}\n  ],\n  \"loops\": [\n{}\n  ]\n}}",
+        grid.width(),
+        grid.height(),
+        paths.join(",\n"),
+        cycles.join(",\n")
+    )
+}
+
+/// the centre of the cell at `pos` in SVG coordinates
+fn centre(pos: Point) -> (usize, usize) {
+    (
+        pos.x as usize * CELL + CELL / 2,
+        pos.y as usize * CELL + CELL / 2,
+    )
+}
+
+/// the steps as SVG path data, turning on the spot doesn't add a point
+///
+/// a guard wrapping round to the opposite edge starts a new line there
+/// rather than drawing one back across the map
+fn route_data(steps: &[PathStep]) -> String {
+    let mut commands: Vec<String> = Vec::new();
+    let mut last: Option<Point> = None;
+
+    for step in steps {
+        if last == Some(step.pos) {
+            continue;
+        }
+
+        let (x, y) = centre(step.pos);
+        let adjacent = last.is_some_and(|last| last.manhattan(step.pos) == 1);
+        commands.push(format!("{}{x},{y}", if adjacent { 'L' } else { 'M' }));
+        last = Some(step.pos);
+    }
+
+    commands.join(" ")
+}
+
+fn rect(svg: &mut String, pos: Point, fill: &str) {
+    svg.push_str(&format!(
+        "  <rect x=\"{}\" y=\"{}\" width=\"{CELL}\" height=\"{CELL}\" fill=\"{fill}\"/>\n",
+        pos.x as usize * CELL,
+        pos.y as usize * CELL
+    ));
+}
+
+/// draws the map with every guard's route and, for each loop cycle, its
+/// obstruction and the cycle it causes
+///
+/// routes start at a circle, cycles are drawn dashed on top of the routes
+pub fn report_to_svg(grid: &Grid<char>, paths: &[GuardPath], cycles: &[LoopCycle]) -> String {
+    let width = grid.width() * CELL;
+    let height = grid.height() * CELL;
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" viewBox=\"0 0 {width} {height}\">\n"
+    );
+
+    svg.push_str(&format!(
+        "  <rect width=\"{width}\" height=\"{height}\" fill=\"#ffffff\"/>\n"
+    ));
+    for (pos, &ch) in grid.iter() {
+        if !is_visitable(ch) {
+            rect(&mut svg, pos, "#333333");
+        }
+    }
+
+    for (i, path) in paths.iter().enumerate() {
+        let colour = ROUTE_COLOURS[i % ROUTE_COLOURS.len()];
+        svg.push_str(&format!(
+            "  <path d=\"{}\" fill=\"none\" stroke=\"{colour}\" stroke-width=\"2\" stroke-linejoin=\"round\"/>\n",
+            route_data(&path.steps)
+        ));
+        if let Some(start) = path.steps.first() {
+            let (x, y) = centre(start.pos);
+            svg.push_str(&format!(
+                "  <circle cx=\"{x}\" cy=\"{y}\" r=\"{}\" fill=\"{colour}\"/>\n",
+                CELL / 2
+            ));
+        }
+    }
+
+    for cycle in cycles {
+        rect(&mut svg, cycle.obstruction, LOOP_OBSTRUCTION_COLOUR);
+        // ending back on the first point draws the whole loop
+        let mut steps = cycle.cycle.clone();
+        steps.extend(cycle.cycle.first().copied());
+        svg.push_str(&format!(
+            "  <path d=\"{}\" fill=\"none\" stroke=\"{CYCLE_COLOUR}\" stroke-width=\"1\" stroke-dasharray=\"3,2\" stroke-opacity=\"0.6\"/>\n",
+            route_data(&steps)
+        ));
+    }
+
+    svg.push_str("</svg>\n");
+    svg
+}
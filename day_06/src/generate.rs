@@ -0,0 +1,75 @@
+use grid::{Direction, Grid, Point};
+
+use crate::oracle::guard_exits;
+use crate::{EdgeMode, Guard, TurnPolicy, OBSTRUCTION, OPEN};
+
+/// how many maps `generate_map` tries before giving up
+const MAX_ATTEMPTS: usize = 1000;
+
+/// splitmix64, small and good enough for laying out obstructions
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+    /// a float in `0.0..1.0`
+    fn unit(&mut self) -> f64 {
+        (self.next() >> 11) as f64 / (1u64 << 53) as f64
+    }
+    fn below(&mut self, n: usize) -> usize {
+        (self.next() % n as u64) as usize
+    }
+}
+
+/// a random `width` x `height` patrol map with exactly one `^`
+///
+/// each cell is an obstruction with probability `density`, and the guard
+/// starts on a random open cell. maps the guard never walks out of are
+/// thrown away, as the puzzle always lets the guard leave, so the same seed
+/// always gives the same map. None when no valid map turned up, e.g. at a
+/// density close to 1
+pub fn generate_map(width: usize, height: usize, density: f64, seed: u64) -> Option<String> {
+    if width == 0 || height == 0 {
+        return None;
+    }
+
+    let mut rng = Rng(seed);
+
+    for _ in 0..MAX_ATTEMPTS {
+        let cells: Vec<char> = (0..width * height)
+            .map(|_| {
+                if rng.unit() < density {
+                    OBSTRUCTION
+                } else {
+                    OPEN
+                }
+            })
+            .collect();
+        let mut grid = Grid::from_cells(width, height, cells);
+
+        let open: Vec<Point> = grid.find_all(&OPEN).collect();
+        if open.is_empty() {
+            continue;
+        }
+
+        let start = open[rng.below(open.len())];
+        if !guard_exits(
+            &grid,
+            Guard::new(start, Direction::N),
+            &TurnPolicy::right(),
+            EdgeMode::Exit,
+        ) {
+            continue;
+        }
+
+        grid.set(start, '^');
+        return Some(grid.to_string());
+    }
+
+    None
+}
@@ -0,0 +1,207 @@
+use grid::{Direction, Grid, Point};
+
+use crate::{is_visitable, EdgeMode, Guard, TurnPolicy};
+
+/// For every open cell and heading, the cell where a guard walking straight
+/// ahead stops because the next cell is blocked
+///
+/// With the table a patrol can hop from turn to turn instead of stepping one
+/// cell at a time. A single extra obstruction doesn't need a rebuilt table,
+/// `stop_with` checks whether it cuts the current hop short.
+pub struct JumpTable {
+    width: usize,
+    height: usize,
+    edge: EdgeMode,
+    /// `width * height * 4` entries indexed by `cell_index * 4 + heading`,
+    /// `None` when the guard walks off the grid, or on a wrapping map walks
+    /// round the same line forever
+    stops: Vec<Option<Point>>,
+}
+
+/// the four headings a guard can have, in table order
+const HEADINGS: [Direction; 4] = Direction::CARDINAL;
+
+fn heading_index(dir: Direction) -> usize {
+    match dir {
+        Direction::N => 0,
+        Direction::E => 1,
+        Direction::S => 2,
+        Direction::W => 3,
+        _ => panic!("the guard only faces cardinal directions"),
+    }
+}
+
+impl JumpTable {
+    pub fn new(grid: &Grid<char>, edge: EdgeMode) -> Self {
+        let width = grid.width();
+        let height = grid.height();
+        let mut table = JumpTable {
+            width,
+            height,
+            edge,
+            stops: vec![None; width * height * 4],
+        };
+
+        for dir in HEADINGS {
+            // walk each line backwards from the edge the guard would leave
+            // by, so the stop for the cell ahead is always known already
+            let edges: Vec<Point> = grid
+                .points()
+                .filter(|p| !grid.contains(p.step(dir)))
+                .collect();
+
+            for edge_pos in edges {
+                let line: Vec<Point> = grid
+                    .ray(edge_pos, dir.reverse().vector())
+                    .map(|(p, _)| p)
+                    .collect();
+                table.fill_line(grid, dir, &line);
+            }
+        }
+
+        table
+    }
+    /// fills in the stops along `line`, which runs backwards against `dir`
+    /// starting at the edge
+    fn fill_line(&mut self, grid: &Grid<char>, dir: Direction, line: &[Point]) {
+        let blocked = |pos: Point| grid.get(pos).is_some_and(|&c| !is_visitable(c));
+        // on a wrapping map the line is a ring, going round it twice means
+        // the stop from past the edge is known by the second time round
+        let laps = if self.edge == EdgeMode::Wrap { 2 } else { 1 };
+        let mut stop: Option<Point> = None;
+
+        for i in 0..line.len() * laps {
+            let pos = line[i % line.len()];
+            if blocked(pos) {
+                continue;
+            }
+
+            let blocked_ahead = match i % line.len() {
+                0 => match self.edge {
+                    EdgeMode::Exit => false,
+                    EdgeMode::Wall => true,
+                    EdgeMode::Wrap => blocked(line[line.len() - 1]),
+                },
+                n => blocked(line[n - 1]),
+            };
+            if blocked_ahead {
+                stop = Some(pos);
+            }
+
+            let index = self.index(pos, dir);
+            self.stops[index] = stop;
+        }
+    }
+    fn index(&self, pos: Point, dir: Direction) -> usize {
+        (pos.y as usize * self.width + pos.x as usize) * 4 + heading_index(dir)
+    }
+    pub fn width(&self) -> usize {
+        self.width
+    }
+    pub fn height(&self) -> usize {
+        self.height
+    }
+    pub fn edge(&self) -> EdgeMode {
+        self.edge
+    }
+    /// where the guard at `pos` facing `dir` stops, None when they walk off
+    /// the grid instead
+    pub fn stop(&self, pos: Point, dir: Direction) -> Option<Point> {
+        self.stops[self.index(pos, dir)]
+    }
+    /// like `stop` but with an extra obstruction at `obstruction`
+    pub fn stop_with(&self, pos: Point, dir: Direction, obstruction: Point) -> Option<Point> {
+        let stop = self.stop(pos, dir);
+        let to_obstruction = obstruction - pos;
+        let step = dir.vector();
+
+        if to_obstruction.dx * step.dy != 0 || to_obstruction.dy * step.dx != 0 {
+            return stop;
+        }
+
+        // how many steps ahead a point on the same line is. on a wrapping
+        // map everything on the line is ahead, going round if need be
+        let line_len = if step.dx == 0 {
+            self.height
+        } else {
+            self.width
+        } as isize;
+        let distance = |to: Point| {
+            let along = (to - pos).dx * step.dx + (to - pos).dy * step.dy;
+            match self.edge {
+                EdgeMode::Wrap => along.rem_euclid(line_len),
+                _ => along,
+            }
+        };
+
+        let ahead = distance(obstruction);
+        if ahead < 1 {
+            return stop;
+        }
+
+        let reach = stop.map_or(isize::MAX, distance);
+        if ahead <= reach {
+            Some(self.wrap(pos + step * (ahead - 1)))
+        } else {
+            stop
+        }
+    }
+    fn wrap(&self, pos: Point) -> Point {
+        Point::new(
+            pos.x.rem_euclid(self.width as isize),
+            pos.y.rem_euclid(self.height as isize),
+        )
+    }
+}
+
+/// Reusable scratch space for loop checks on the same grid
+///
+/// Marks are stamped with a generation number so the visited set doesn't
+/// have to be cleared between candidate obstructions. There is one mark per
+/// cell, heading and turn policy phase.
+pub struct LoopChecker<'a> {
+    table: &'a JumpTable,
+    policy: &'a TurnPolicy,
+    marks: Vec<u32>,
+    generation: u32,
+}
+
+impl<'a> LoopChecker<'a> {
+    pub fn new(table: &'a JumpTable, policy: &'a TurnPolicy) -> Self {
+        LoopChecker {
+            table,
+            policy,
+            marks: vec![0; table.width * table.height * 4 * policy.period()],
+            generation: 0,
+        }
+    }
+    /// true when `guard` never leaves the grid once `obstruction` is added
+    ///
+    /// only turn points are recorded, a loop always repeats one of them
+    pub fn loops_with(&mut self, guard: Guard, obstruction: Point) -> bool {
+        self.generation += 1;
+        if self.generation == u32::MAX {
+            self.marks.fill(0);
+            self.generation = 1;
+        }
+
+        let Guard { mut pos, mut dir } = guard;
+        let mut phase = 0;
+
+        loop {
+            // with no stop the guard leaves, or on a wrapping map goes round
+            // the same line forever
+            let Some(stop) = self.table.stop_with(pos, dir, obstruction) else {
+                return self.table.edge == EdgeMode::Wrap;
+            };
+            pos = stop;
+            (dir, phase) = self.policy.turn(dir, phase);
+
+            let index = self.table.index(pos, dir) * self.policy.period() + phase;
+            if self.marks[index] == self.generation {
+                return true;
+            }
+            self.marks[index] = self.generation;
+        }
+    }
+}
@@ -0,0 +1,394 @@
+use std::collections::HashSet;
+use std::num::NonZeroUsize;
+use std::thread;
+
+use common::{ParseError, Solution};
+use grid::{Direction, Grid, Point};
+
+mod analysis;
+pub mod export;
+mod generate;
+mod jump;
+pub mod oracle;
+mod path;
+mod patrol;
+mod simulate;
+
+pub use analysis::{best_loop_obstructions, min_trap_obstructions};
+pub use generate::generate_map;
+pub use jump::{JumpTable, LoopChecker};
+pub use path::{get_loop_cycles, get_path, GuardPath, LoopCycle, PathEnd, PathStep};
+pub use patrol::{Ahead, EdgeMode, Guard, Turn, TurnPolicy};
+pub use simulate::{Simulation, State, Style};
+
+/// the sample lab map used when no input file is given
+pub const EXAMPLE: &str = include_str!("../test.txt");
+
+/// the lab map, the guards' starting positions, how they turn and what the
+/// edge of the map does
+///
+/// parsing always gives the puzzle's turn right policy and exit edges, the
+/// binary can swap in others
+pub struct Lab {
+    pub grid: Grid<char>,
+    pub guards: Vec<Guard>,
+    pub policy: TurnPolicy,
+    pub edge: EdgeMode,
+}
+
+// solution part 1
+// walk the 2d array until the next index would leave the bounds and
+// keep track of each "distinct" position visited - the answer will
+// be the len() of the set
+//
+// solution part 2
+// given the guard starts in the same position, the distinct
+// visited positions from part 1 can be used as candidates
+// for new obstruction placement
+//
+// after adding a new obstruction to one of the known visit
+// positions, run the same check as in part one, but
+// verify that the guard never exits the grid
+//
+// the guard's next move only depends on their position and
+// heading, so the moment they stand somewhere facing the same
+// way as before they are stuck in a loop. tracking the
+// (position, direction) states seen so far makes that a single
+// set lookup per step
+//
+// walking one cell at a time is still slow for thousands of
+// candidates, so a jump table precomputes where the guard stops
+// from every cell and heading. the walk then hops from turn to
+// turn, only checking whether the one added obstruction cuts a
+// hop short
+//
+// with several guards each one patrols on their own, they don't
+// block each other. part 1 counts the cells any of them visit and
+// part 2 the obstructions that trap at least one of them
+//
+// when the edge wraps around or is a wall nobody ever leaves, so
+// every guard loops before any obstruction is added. an
+// obstruction off a looping guard's path can't free them, so then
+// every open cell is a candidate, not just the visited ones
+/// Day 6: Guard Gallivant
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Lab;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let (grid, guards) = parse_map(input)?;
+
+        Ok(Lab {
+            grid,
+            guards,
+            policy: TurnPolicy::default(),
+            edge: EdgeMode::default(),
+        })
+    }
+
+    fn part1(lab: &Self::Input) -> usize {
+        get_all_visited_positions(&lab.grid, &lab.guards, &lab.policy, lab.edge).len()
+    }
+
+    fn part2(lab: &Self::Input) -> usize {
+        part2_with_threads(lab, default_threads())
+    }
+}
+
+/// part 2 with the candidate obstructions split across `threads` threads
+pub fn part2_with_threads(lab: &Lab, threads: usize) -> usize {
+    let candidates = get_candidate_positions(&lab.grid, &lab.guards, &lab.policy, lab.edge);
+
+    get_total_loop_obstruction_positions_with_threads(
+        &lab.grid,
+        &candidates,
+        &lab.guards,
+        &lab.policy,
+        lab.edge,
+        threads,
+    )
+}
+
+/// where an extra obstruction could trap a guard
+///
+/// the visited positions when every guard leaves the map, every open cell
+/// when one of them is already stuck in a loop. never a guard's start
+pub fn get_candidate_positions(
+    grid: &Grid<char>,
+    guards: &[Guard],
+    policy: &TurnPolicy,
+    edge: EdgeMode,
+) -> HashSet<Point> {
+    let walks: Vec<Walk> = guards
+        .iter()
+        .map(|&guard| walk(grid, guard, policy, edge))
+        .collect();
+
+    let mut candidates: HashSet<Point> = if walks.iter().all(|w| w.exit.is_some()) {
+        walks.into_iter().flat_map(|w| w.visited).collect()
+    } else {
+        grid.find_all(&OPEN).collect()
+    };
+
+    for guard in guards {
+        candidates.remove(&guard.pos);
+    }
+
+    candidates
+}
+
+/// one thread per available core, or a single thread when that is unknown
+pub fn default_threads() -> usize {
+    thread::available_parallelism().map_or(1, NonZeroUsize::get)
+}
+
+/// the open floor tile, anything else blocks the guard
+pub const OPEN: char = '.';
+/// the tile used for obstructions
+pub const OBSTRUCTION: char = '#';
+
+/// counts the candidate positions where a single new obstruction would trap
+/// at least one of the guards in a loop
+pub fn get_total_loop_obstruction_positions(
+    grid: &Grid<char>,
+    candidates: &HashSet<Point>,
+    guards: &[Guard],
+    policy: &TurnPolicy,
+    edge: EdgeMode,
+) -> usize {
+    get_total_loop_obstruction_positions_with_threads(grid, candidates, guards, policy, edge, 1)
+}
+
+/// like `get_total_loop_obstruction_positions` but checks the candidates on
+/// `threads` threads
+///
+/// every candidate is checked as an overlay on the shared jump table, so the
+/// threads never touch the grid and the count doesn't depend on how the
+/// candidates are split
+pub fn get_total_loop_obstruction_positions_with_threads(
+    grid: &Grid<char>,
+    candidates: &HashSet<Point>,
+    guards: &[Guard],
+    policy: &TurnPolicy,
+    edge: EdgeMode,
+    threads: usize,
+) -> usize {
+    let table = JumpTable::new(grid, edge);
+    let candidates: Vec<Point> = candidates
+        .iter()
+        .copied()
+        .filter(|pos| !guards.iter().any(|guard| &guard.pos == pos))
+        .collect();
+
+    let count_loops = |chunk: &[Point]| {
+        let mut checker = LoopChecker::new(&table, policy);
+        chunk
+            .iter()
+            .filter(|&&obstruction_pos| {
+                guards
+                    .iter()
+                    .any(|&guard| checker.loops_with(guard, obstruction_pos))
+            })
+            .count()
+    };
+
+    let threads = threads.max(1);
+    if threads == 1 || candidates.len() < 2 {
+        return count_loops(&candidates);
+    }
+
+    let chunk_size = candidates.len().div_ceil(threads);
+    thread::scope(|scope| {
+        let handles: Vec<_> = candidates
+            .chunks(chunk_size)
+            .map(|chunk| scope.spawn(|| count_loops(chunk)))
+            .collect();
+
+        handles
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .sum()
+    })
+}
+
+/// true when `guard` never leaves the grid
+///
+/// the walk stops as soon as the guard repeats a (position, direction,
+/// phase) state, or steps off the grid
+pub fn guard_loops(grid: &Grid<char>, guard: Guard, policy: &TurnPolicy, edge: EdgeMode) -> bool {
+    let Guard { mut pos, mut dir } = guard;
+    let mut phase = 0;
+    let mut seen: HashSet<(Point, Direction, usize)> = HashSet::new();
+
+    seen.insert((pos, dir, phase));
+
+    loop {
+        match edge.ahead(grid, pos, dir) {
+            Ahead::Off => return false,
+            Ahead::Open(next) => pos = next,
+            Ahead::Blocked => (dir, phase) = policy.turn(dir, phase),
+        }
+
+        // turns count as states too, so a guard boxed in on all four
+        // sides is caught after spinning once
+        if !seen.insert((pos, dir, phase)) {
+            return true;
+        }
+    }
+}
+
+/// the outcome of one guard's patrol
+#[derive(Debug, Clone)]
+pub struct Walk {
+    /// every position the guard stood on
+    pub visited: HashSet<Point>,
+    /// cells walked forward, the path length
+    pub steps: usize,
+    /// turns made in front of an obstruction
+    pub turns: usize,
+    /// the last position on the map and the heading the guard left by,
+    /// `step` counting moves and turns like `get_path`. None when the guard
+    /// is stuck in a loop
+    pub exit: Option<PathStep>,
+}
+
+/// walks `guard` until they leave the grid and records where they went
+///
+/// a guard stuck in a loop is stopped once they repeat a turn, by then
+/// they have been everywhere they will ever go. on a wrapping map a guard
+/// can also loop without turning, down a row or column with no
+/// obstructions, which shows as walking further straight ahead than the
+/// map is long
+pub fn walk(grid: &Grid<char>, guard: Guard, policy: &TurnPolicy, edge: EdgeMode) -> Walk {
+    let Guard { mut pos, mut dir } = guard;
+    let mut phase = 0;
+    let mut visited: HashSet<Point> = HashSet::new();
+    let mut turns: HashSet<(Point, Direction, usize)> = HashSet::new();
+    let mut steps = 0;
+    let mut straight = 0;
+    let mut turn_count = 0;
+    let mut exit = None;
+    let longest_line = grid.width().max(grid.height());
+
+    visited.insert(pos);
+
+    loop {
+        match edge.ahead(grid, pos, dir) {
+            Ahead::Off => {
+                exit = Some(PathStep {
+                    step: steps + turn_count,
+                    pos,
+                    dir,
+                });
+                break;
+            }
+            Ahead::Blocked => {
+                (dir, phase) = policy.turn(dir, phase);
+                turn_count += 1;
+                straight = 0;
+                if !turns.insert((pos, dir, phase)) {
+                    break;
+                }
+            }
+            Ahead::Open(next) => {
+                pos = next;
+                steps += 1;
+                straight += 1;
+                visited.insert(pos);
+                if straight > longest_line {
+                    break;
+                }
+            }
+        }
+    }
+
+    Walk {
+        visited,
+        steps,
+        turns: turn_count,
+        exit,
+    }
+}
+
+/// walks `guard` until they leave the grid and returns every position they
+/// stood on
+pub fn get_distinct_visited_positions(
+    grid: &Grid<char>,
+    guard: Guard,
+    policy: &TurnPolicy,
+    edge: EdgeMode,
+) -> HashSet<Point> {
+    walk(grid, guard, policy, edge).visited
+}
+
+/// every position any of the guards stood on
+pub fn get_all_visited_positions(
+    grid: &Grid<char>,
+    guards: &[Guard],
+    policy: &TurnPolicy,
+    edge: EdgeMode,
+) -> HashSet<Point> {
+    guards
+        .iter()
+        .flat_map(|&guard| get_distinct_visited_positions(grid, guard, policy, edge))
+        .collect()
+}
+
+/// the glyph for a guard facing `dir`
+pub fn guard_glyph(dir: Direction) -> char {
+    match dir {
+        Direction::N => '^',
+        Direction::E => '>',
+        Direction::S => 'v',
+        Direction::W => '<',
+        _ => panic!("the guard only faces cardinal directions"),
+    }
+}
+
+/// the heading of a guard drawn as `ch`, None for any other tile
+pub fn guard_heading(ch: char) -> Option<Direction> {
+    match ch {
+        '^' => Some(Direction::N),
+        '>' => Some(Direction::E),
+        'v' => Some(Direction::S),
+        '<' => Some(Direction::W),
+        _ => None,
+    }
+}
+
+/// true when the guard can stand on `ch`
+pub fn is_visitable(ch: char) -> bool {
+    ch == OPEN
+}
+
+/// parses the map and replaces every guard tile (`^`, `>`, `v` or `<`) with
+/// open floor
+///
+/// returns the map and the guards in reading order, a map without a guard
+/// is an error
+pub fn parse_map(input: &str) -> Result<(Grid<char>, Vec<Guard>), ParseError> {
+    let mut grid = Grid::parse(input)?;
+    let guards: Vec<Guard> = grid
+        .iter()
+        .filter_map(|(pos, &ch)| guard_heading(ch).map(|dir| Guard::new(pos, dir)))
+        .collect();
+
+    if guards.is_empty() {
+        let first_line = input.lines().next().unwrap_or("");
+        return Err(ParseError::new(
+            1,
+            1,
+            first_line,
+            "no guard on the map, expected one of ^ > v <",
+        ));
+    }
+
+    for guard in &guards {
+        grid.set(guard.pos, OPEN);
+    }
+
+    Ok((grid, guards))
+}
@@ -1,241 +1,467 @@
-use std::{collections::HashSet, env, fs::read_to_string};
-
-fn main() {
-    // solution part 1
-    // walk the 2d array until the next index would leave the bounds and
-    // keep track of each "distinct" position visited - the answer will
-    // be the len() of the set
-    //
-    // solution part 2
-    // given the guard starts in the same position, the distinct
-    // visited positions from part 1 can be used as candidates
-    // for new obstruction placement
-    //
-    // after adding a new obstruction to one of the known visit
-    // positions, run the same check as in part one, but
-    // verify that the guard never exits the grid
-    //
-    // we cand determine that the guard is stuck in a loop by
-    // predetermining the maximum distinct visitable positions
-    // on the entire grid (all the '.'s), then track how many
-    // steps the guard has taken since the last "new" grid
-    // position has been visited and if that number is >=
-    // the max visitable positions in the grid, then the
-    // guard must be stuck in a loop
-
-    let args: Vec<String> = env::args().collect();
-    let (map, start_pos) = get_input(args.get(1));
-    let (row, col) = start_pos;
-    let grid = Grid::new(map);
-
-    let distinct_visited_positions = get_distinct_visited_positions(&grid, GridPos::new(row, col));
-    let total_distinct_visits = distinct_visited_positions.len();
-    let total_loop_obstruction_positions = get_total_loop_obstruction_positions(
-        &grid,
-        &distinct_visited_positions,
-        GridPos::new(row, col),
-    );
-    println!("part 1 answer: {total_distinct_visits}");
-    println!("part 2 answer: {total_loop_obstruction_positions}");
+use std::env;
+use std::fs;
+use std::io::{stdout, Write};
+use std::path::Path;
+use std::process;
+use std::thread;
+use std::time::Duration;
+
+use common::Solution;
+use day_06::{
+    export, oracle, Day06, EdgeMode, Lab, Simulation, State, Style, TurnPolicy, EXAMPLE, OPEN,
+};
+use grid::Point;
+
+// usage
+// day_06 [input] [--threads N] [--turn POLICY] [--edge MODE] [--verbose] [--oracle]
+//     prints both answers, and with --verbose where each guard left the map,
+//     how far they walked and how often they turned. --oracle works the
+//     answers out by brute force instead
+// day_06 simulate [input] [--turn POLICY] [--edge MODE] [--guard N] [--delay MS] [--steps N]
+//                 [--obstruction X,Y] [--plain]
+//     replays the patrol frame by frame
+// day_06 export [input] [--turn POLICY] [--edge MODE] [--format json|svg] [--cycles]
+//               [--obstruction X,Y]
+//     prints every guard's path as JSON or as an SVG drawing of the map
+// day_06 analyse [input] [--turn POLICY] [--edge MODE] [--top K]
+//                [--max-obstructions N]
+//     finds the fewest added obstructions that trap a guard and ranks the
+//     part 2 obstructions by the length of the loop they cause
+// day_06 generate [--size N|WxH] [--density D] [--seed N] [--output FILE]
+//                 [--oracle]
+//     prints a random map with one guard the guard can walk out of
+//
+// --turn POLICY       how guards turn at an obstruction: right (the default),
+//                     left, reverse, or a repeating sequence of R, L and B
+//                     (back) such as RRL
+// --edge MODE         what the edge of the map does: exit (the default),
+//                     wrap round to the opposite edge, or wall
+//
+// simulate options
+// --guard N           which guard to follow, counting from 1 in reading order
+// --delay MS          pause between frames, defaults to 50
+// --steps N           guard steps per frame, defaults to 1
+// --obstruction X,Y   adds an obstruction at column X, row Y (0 based)
+// --plain             no colours or cursor movement, frames are just printed
+//
+// export options
+// --format FORMAT     json (the default) or svg
+// --cycles            adds the loop cycle of every part 2 obstruction
+// --obstruction X,Y   adds an obstruction to the exported paths
+//
+// analyse options
+// --top K                  how many ranked obstructions to list, defaults to 10
+// --max-obstructions N     gives up on trapping a guard with more than N
+//                          obstructions, defaults to 3
+//
+// generate options
+// --size N|WxH        the map size, defaults to 10
+// --density D         the chance of each cell being an obstruction, defaults
+//                     to 0.1
+// --seed N            the same seed always gives the same map, defaults to 1
+// --output FILE       writes the map to FILE instead of printing it
+// --oracle            also works out the answers by brute force, they are
+//                     written to FILE's .answers.toml, or printed after the
+//                     map
+
+/// what the binary was asked to do
+#[derive(PartialEq, Eq)]
+enum Command {
+    Answers,
+    Simulate,
+    Export,
+    Analyse,
+    Generate,
 }
 
-fn get_total_loop_obstruction_positions(
-    grid: &Grid,
-    distinct_visited_positions: &HashSet<GridPos>,
-    start: GridPos,
-) -> usize {
-    let mut result: usize = 0;
+/// the output format of `export`
+#[derive(PartialEq, Eq)]
+enum Format {
+    Json,
+    Svg,
+}
 
-    let mut test_data: Vec<Vec<char>> = Vec::new();
+/// the command line after the command
+struct Options {
+    path: Option<String>,
+    threads: usize,
+    policy: TurnPolicy,
+    edge: EdgeMode,
+    guard: usize,
+    delay: Duration,
+    steps: usize,
+    obstruction: Option<Point>,
+    plain: bool,
+    verbose: bool,
+    format: Format,
+    cycles: bool,
+    oracle: bool,
+    size: (usize, usize),
+    density: f64,
+    seed: u64,
+    output: Option<String>,
+    top: usize,
+    max_obstructions: usize,
+}
 
-    for r in &grid.data {
-        let mut row: Vec<char> = Vec::new();
-        for c in r {
-            row.push(*c);
+impl Options {
+    fn parse(args: &[String]) -> Result<Self, String> {
+        let mut options = Options {
+            path: None,
+            threads: day_06::default_threads(),
+            policy: TurnPolicy::default(),
+            edge: EdgeMode::default(),
+            guard: 1,
+            delay: Duration::from_millis(50),
+            steps: 1,
+            obstruction: None,
+            plain: false,
+            verbose: false,
+            format: Format::Json,
+            cycles: false,
+            oracle: false,
+            size: (10, 10),
+            density: 0.1,
+            seed: 1,
+            output: None,
+            top: 10,
+            max_obstructions: 3,
+        };
+        let mut args = args.iter();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--threads" => options.threads = positive(arg, args.next())?,
+                "--guard" => options.guard = positive(arg, args.next())?,
+                "--turn" => {
+                    let value = args.next().ok_or("--turn needs a policy")?;
+                    options.policy = value.parse()?;
+                }
+                "--edge" => {
+                    let value = args.next().ok_or("--edge needs exit, wrap or wall")?;
+                    options.edge = value.parse()?;
+                }
+                "--steps" => options.steps = positive(arg, args.next())?,
+                "--delay" => {
+                    let value = args
+                        .next()
+                        .ok_or("--delay needs a number of milliseconds")?;
+                    let ms = value
+                        .parse::<u64>()
+                        .map_err(|_| format!("invalid delay: {value}"))?;
+                    options.delay = Duration::from_millis(ms);
+                }
+                "--obstruction" => {
+                    let value = args.next().ok_or("--obstruction needs X,Y")?;
+                    options.obstruction = Some(parse_point(value)?);
+                }
+                "--plain" => options.plain = true,
+                "--verbose" | "-v" => options.verbose = true,
+                "--cycles" => options.cycles = true,
+                "--oracle" => options.oracle = true,
+                "--top" => options.top = positive(arg, args.next())?,
+                "--max-obstructions" => options.max_obstructions = positive(arg, args.next())?,
+                "--size" => {
+                    let value = args.next().ok_or("--size needs N or WxH")?;
+                    options.size = parse_size(value)?;
+                }
+                "--density" => {
+                    let value = args.next().ok_or("--density needs a number")?;
+                    options.density = match value.parse::<f64>() {
+                        Ok(d) if (0.0..=1.0).contains(&d) => d,
+                        _ => return Err(format!("invalid density: {value}, expected 0 to 1")),
+                    };
+                }
+                "--seed" => {
+                    let value = args.next().ok_or("--seed needs a number")?;
+                    options.seed = value
+                        .parse()
+                        .map_err(|_| format!("invalid seed: {value}"))?;
+                }
+                "--output" => {
+                    let value = args.next().ok_or("--output needs a file")?;
+                    options.output = Some(String::from(value));
+                }
+                "--format" => {
+                    options.format = match args.next().map(String::as_str) {
+                        Some("json") => Format::Json,
+                        Some("svg") => Format::Svg,
+                        _ => return Err(String::from("--format needs json or svg")),
+                    }
+                }
+                flag if flag.starts_with("--") => return Err(format!("unknown option: {flag}")),
+                path => options.path = Some(String::from(path)),
+            }
         }
-        test_data.push(row);
-    }
-
-    let mut test_grid = Grid::new(test_data);
-
-    let total_visitable_positions = test_grid.total_visitable_positions();
 
-    for obstruction_pos in distinct_visited_positions {
-        dbg!(&obstruction_pos);
-        if obstruction_pos == &start {
-            continue;
-        }
+        Ok(options)
+    }
+}
 
-        test_grid.replace(obstruction_pos, '#');
+/// parses the value after `flag` as a count of at least 1
+fn positive(flag: &str, value: Option<&String>) -> Result<usize, String> {
+    match value.map(|v| v.parse::<usize>()) {
+        Some(Ok(n)) if n > 0 => Ok(n),
+        _ => Err(format!("{flag} needs a positive count")),
+    }
+}
 
-        // if this exceed total_visitable_positions, we're in a loop
-        let mut steps_since_last_distinct_position = 0;
-        let mut pos = GridPos::new(start.row, start.col);
-        let mut dir = Direction::up();
-        let mut visited: HashSet<GridPos> = HashSet::new();
+fn parse_point(value: &str) -> Result<Point, String> {
+    let invalid = || format!("invalid position: {value}, expected X,Y");
+    let (x, y) = value.split_once(',').ok_or_else(invalid)?;
 
-        // yes, this sort of duplicates what's in the fn for part 1 answer
-        while let Some(next_pos) = test_grid.next(&pos, &dir) {
-            let ch = test_grid.char_at(&next_pos);
-            if !test_grid.is_visitable(&ch) {
-                dir = dir.rotated90();
-                continue;
-            }
+    Ok(Point::new(
+        x.trim().parse().map_err(|_| invalid())?,
+        y.trim().parse().map_err(|_| invalid())?,
+    ))
+}
 
-            if visited.contains(&pos) {
-                steps_since_last_distinct_position += 1;
-            } else {
-                steps_since_last_distinct_position = 0;
-            }
+/// `N` for a square map or `WxH`
+fn parse_size(value: &str) -> Result<(usize, usize), String> {
+    let invalid = || format!("invalid size: {value}, expected N or WxH");
+    let (w, h) = value.split_once('x').unwrap_or((value, value));
+    let w = w.parse::<usize>().map_err(|_| invalid())?;
+    let h = h.parse::<usize>().map_err(|_| invalid())?;
 
-            if steps_since_last_distinct_position > total_visitable_positions {
-                result += 1;
-                break;
-            }
+    if w == 0 || h == 0 {
+        return Err(invalid());
+    }
 
-            visited.insert(GridPos::new(next_pos.row, next_pos.col));
-            pos = next_pos;
-        }
+    Ok((w, h))
+}
 
-        test_grid.replace(obstruction_pos, '.');
+fn main() -> Result<(), common::Error> {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let (command, args) = match args.first().map(String::as_str) {
+        Some("simulate") => (Command::Simulate, &args[1..]),
+        Some("export") => (Command::Export, &args[1..]),
+        Some("analyse") => (Command::Analyse, &args[1..]),
+        Some("generate") => (Command::Generate, &args[1..]),
+        _ => (Command::Answers, &args[..]),
+    };
+
+    let options = Options::parse(args).unwrap_or_else(|msg| {
+        eprintln!("{msg}");
+        process::exit(2);
+    });
+
+    if command == Command::Generate {
+        return generate(&options);
     }
 
-    println!("{total_visitable_positions}");
+    let mut lab = common::parse_input::<Day06>(options.path.as_ref(), EXAMPLE)?;
+    lab.policy = options.policy.clone();
+    lab.edge = options.edge;
 
-    result
-}
-fn get_distinct_visited_positions(grid: &Grid, start: GridPos) -> HashSet<GridPos> {
-    let mut pos = GridPos::new(start.row, start.col);
-    let mut dir = Direction::up();
-    let mut visited: HashSet<GridPos> = HashSet::new();
-
-    visited.insert(GridPos::new(start.row, start.col));
-
-    while let Some(next_pos) = grid.next(&pos, &dir) {
-        let ch = grid.char_at(&next_pos);
-        if !grid.is_visitable(&ch) {
-            dir = dir.rotated90();
-            continue;
+    match command {
+        Command::Answers if options.oracle => {
+            let (part_1, part_2) = oracle::brute_force(&lab);
+            common::print_answers(part_1, part_2);
         }
-
-        visited.insert(GridPos::new(next_pos.row, next_pos.col));
-        pos = next_pos;
+        Command::Answers => {
+            common::print_answers(
+                Day06::part1(&lab),
+                day_06::part2_with_threads(&lab, options.threads),
+            );
+            if options.verbose {
+                print_walks(&lab);
+            }
+        }
+        Command::Simulate => replay(&lab, &options),
+        Command::Export => export(&lab, &options),
+        Command::Analyse => analyse(&lab, &options),
+        Command::Generate => unreachable!(),
     }
 
-    visited
+    Ok(())
 }
 
-#[derive(Debug)]
-struct Grid {
-    data: Vec<Vec<char>>,
+/// prints where each guard left the map and how they got there
+fn print_walks(lab: &Lab) {
+    for (i, &guard) in lab.guards.iter().enumerate() {
+        let walk = day_06::walk(&lab.grid, guard, &lab.policy, lab.edge);
+        let Point { x, y } = guard.pos;
+
+        println!();
+        println!("guard {} from ({x}, {y}) facing {}", i + 1, guard.dir);
+        match walk.exit {
+            Some(exit) => println!(
+                "  exit: ({}, {}) facing {} at step {}",
+                exit.pos.x, exit.pos.y, exit.dir, exit.step
+            ),
+            None => println!("  exit: none, stuck in a loop"),
+        }
+        println!("  path length: {} steps", walk.steps);
+        println!("  turns: {}", walk.turns);
+        println!("  visited: {} cells", walk.visited.len());
+    }
 }
 
-impl Grid {
-    fn new(data: Vec<Vec<char>>) -> Self {
-        Grid { data }
-    }
-    /// returns the next grid position in the provided direction relative
-    /// to the start position
-    /// returns None if the next position would be out of the grid bounds
-    fn next(&self, start: &GridPos, dir: &Direction) -> Option<GridPos> {
-        let next_row = (start.row as isize) + dir.y;
-        let next_col = (start.col as isize) + dir.x;
-
-        if next_row < 0 || next_row >= self.data.len() as isize {
-            return None;
+/// animates the patrol until the guard leaves or loops
+fn replay(lab: &Lab, options: &Options) {
+    let Some(&guard) = lab.guards.get(options.guard - 1) else {
+        eprintln!(
+            "no guard {}, the map has {}",
+            options.guard,
+            lab.guards.len()
+        );
+        process::exit(2);
+    };
+
+    check_obstruction(lab, options);
+
+    let style = if options.plain {
+        Style::Plain
+    } else {
+        Style::Ansi
+    };
+    let mut sim = Simulation::new(&lab.grid, guard, &lab.policy, lab.edge, options.obstruction);
+    let mut out = stdout().lock();
+
+    loop {
+        let frame = sim.render(style);
+        let status = sim.status();
+
+        // writes can only fail once stdout is gone, e.g. piped into `head`
+        let written = match style {
+            Style::Ansi => writeln!(out, "\x1b[2J\x1b[H{frame}{status}"),
+            Style::Plain => writeln!(out, "{frame}{status}\n"),
+        };
+        if written.and_then(|_| out.flush()).is_err() || sim.state() != State::Walking {
+            break;
         }
 
-        if next_col < 0 || next_col >= self.data.first().unwrap().len() as isize {
-            return None;
+        for _ in 0..options.steps {
+            sim.step();
         }
-
-        Some(GridPos::new(next_row as usize, next_col as usize))
-    }
-    fn char_at(&self, pos: &GridPos) -> char {
-        *self.data.get(pos.row).unwrap().get(pos.col).unwrap()
-    }
-    fn is_visitable(&self, ch: &char) -> bool {
-        ch == &'.'
-    }
-    fn place_obstruction(&mut self, pos: &GridPos) {
-        self.replace(pos, '#');
-    }
-    fn remove_obstruction(&mut self, pos: &GridPos) {
-        self.replace(pos, '.');
-    }
-    fn replace(&mut self, pos: &GridPos, ch: char) {
-        let r = self.data.get_mut(pos.row).unwrap();
-        r.remove(pos.col);
-        r.insert(pos.col, ch);
-    }
-    fn total_visitable_positions(&self) -> usize {
-        let mut result: usize = 0;
-        for r in self.data.iter() {
-            for ch in r.iter() {
-                if !self.is_visitable(ch) {
-                    continue;
-                }
-                result += 1;
-            }
+        if !options.delay.is_zero() {
+            thread::sleep(options.delay);
         }
-
-        result
     }
 }
 
-#[derive(Debug, Hash, Eq, PartialEq)]
-struct GridPos {
-    row: usize,
-    col: usize,
+/// exits when the extra obstruction isn't on open floor or is on a guard
+fn check_obstruction(lab: &Lab, options: &Options) {
+    if let Some(obstruction) = options.obstruction {
+        let on_guard = lab.guards.iter().any(|g| g.pos == obstruction);
+        if on_guard || lab.grid.get(obstruction) != Some(&OPEN) {
+            eprintln!("the extra obstruction must go on open floor away from the guards");
+            process::exit(2);
+        }
+    }
 }
 
-impl GridPos {
-    fn new(row: usize, col: usize) -> Self {
-        GridPos { row, col }
+/// prints every guard's path, and the loop cycles when asked for
+fn export(lab: &Lab, options: &Options) {
+    check_obstruction(lab, options);
+
+    let paths: Vec<_> = lab
+        .guards
+        .iter()
+        .map(|&guard| {
+            day_06::get_path(&lab.grid, guard, &lab.policy, lab.edge, options.obstruction)
+        })
+        .collect();
+    let cycles = if options.cycles {
+        day_06::get_loop_cycles(&lab.grid, &lab.guards, &lab.policy, lab.edge)
+    } else {
+        Vec::new()
+    };
+
+    match options.format {
+        Format::Json => println!("{}", export::report_to_json(&lab.grid, &paths, &cycles)),
+        Format::Svg => print!("{}", export::report_to_svg(&lab.grid, &paths, &cycles)),
     }
 }
 
-#[derive(Debug)]
-struct Direction {
-    y: isize,
-    x: isize,
-}
+/// prints the smallest trap and the obstructions causing the longest loops
+fn analyse(lab: &Lab, options: &Options) {
+    let trap = day_06::min_trap_obstructions(
+        &lab.grid,
+        &lab.guards,
+        &lab.policy,
+        lab.edge,
+        options.max_obstructions,
+    );
 
-impl Direction {
-    fn new(y: isize, x: isize) -> Self {
-        Direction { y, x }
+    match trap {
+        Some(obstructions) if obstructions.is_empty() => {
+            println!("minimum obstructions to trap a guard: 0, one already loops")
+        }
+        Some(obstructions) => {
+            println!(
+                "minimum obstructions to trap a guard: {}",
+                obstructions.len()
+            );
+            for Point { x, y } in obstructions {
+                println!("  ({x}, {y})");
+            }
+        }
+        None => println!(
+            "minimum obstructions to trap a guard: more than {}",
+            options.max_obstructions
+        ),
     }
-    fn up() -> Self {
-        Direction { y: -1, x: 0 }
+
+    let best =
+        day_06::best_loop_obstructions(&lab.grid, &lab.guards, &lab.policy, lab.edge, options.top);
+
+    println!();
+    println!("longest loops from a single obstruction:");
+    if best.is_empty() {
+        println!("  none");
     }
-    fn rotated90(&self) -> Self {
-        Direction::new(self.x, -self.y)
+    for (rank, cycle) in best.iter().enumerate() {
+        let Point { x, y } = cycle.obstruction;
+        println!(
+            "  {:>3}  ({x}, {y})  guard {}  loop of {} steps",
+            rank + 1,
+            cycle.guard + 1,
+            cycle.cycle.len()
+        );
     }
 }
 
-fn get_input(path: Option<&String>) -> (Vec<Vec<char>>, (usize, usize)) {
-    let mut result: Vec<Vec<char>> = Vec::new();
-    let fallback_path = String::from("test.txt");
-    let mut start_pos: (usize, usize) = (0, 0);
-
-    if let Some(p) = path.or(Some(&fallback_path)) {
-        let lines: Vec<String> = read_to_string(p)
-            .unwrap()
-            .lines()
-            .map(String::from)
-            .collect();
-
-        for row in 0..lines.len() {
-            let mut line: Vec<char> = lines.get(row).unwrap().chars().collect();
-
-            if let Some(col) = line.iter().position(|&c| c == '^') {
-                line[col] = '.';
-                start_pos = (row, col);
+/// prints or writes a random map, along with its brute forced answers
+fn generate(options: &Options) -> Result<(), common::Error> {
+    let (width, height) = options.size;
+    let Some(map) = day_06::generate_map(width, height, options.density, options.seed) else {
+        eprintln!("no map the guard can leave at density {}", options.density);
+        process::exit(1);
+    };
+
+    let answers = options.oracle.then(|| {
+        let lab = Day06::parse(&map).expect("generated maps always parse");
+        let (part_1, part_2) = oracle::brute_force(&lab);
+        format!("part1 = {part_1}\npart2 = {part_2}\n")
+    });
+
+    match &options.output {
+        Some(output) => {
+            let path = Path::new(output);
+            write_file(path, &map)?;
+            if let Some(answers) = answers {
+                let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+                write_file(
+                    &path.with_file_name(format!("{stem}.answers.toml")),
+                    &answers,
+                )?;
+            }
+        }
+        None => {
+            print!("{map}");
+            if let Some(answers) = answers {
+                print!("\n{answers}");
             }
-
-            result.push(line);
         }
     }
 
-    (result, start_pos)
+    Ok(())
+}
+
+fn write_file(path: &Path, contents: &str) -> Result<(), common::Error> {
+    fs::write(path, contents).map_err(|source| common::Error::Io {
+        path: path.to_path_buf(),
+        source,
+    })
 }
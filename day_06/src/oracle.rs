@@ -0,0 +1,85 @@
+use std::collections::HashSet;
+
+use grid::{Direction, Grid, Point};
+
+use crate::{EdgeMode, Guard, Lab, TurnPolicy, OBSTRUCTION, OPEN};
+
+/// walks `guard` one cell at a time and returns every position they stood
+/// on, and whether they got stuck in a loop
+///
+/// every (position, direction, phase) state is remembered, nothing clever
+fn naive_walk(
+    grid: &Grid<char>,
+    guard: Guard,
+    policy: &TurnPolicy,
+    edge: EdgeMode,
+) -> (HashSet<Point>, bool) {
+    let (width, height) = (grid.width() as isize, grid.height() as isize);
+    let Guard { mut pos, mut dir } = guard;
+    let mut phase = 0;
+    let mut visited: HashSet<Point> = HashSet::from([pos]);
+    let mut seen: HashSet<(Point, Direction, usize)> = HashSet::from([(pos, dir, phase)]);
+
+    loop {
+        let mut ahead = pos.step(dir);
+        if !grid.contains(ahead) {
+            match edge {
+                EdgeMode::Exit => return (visited, false),
+                EdgeMode::Wrap => {
+                    ahead = Point::new((ahead.x + width) % width, (ahead.y + height) % height)
+                }
+                // a point off the map reads as None below, which blocks
+                EdgeMode::Wall => {}
+            }
+        }
+
+        match grid.get(ahead) {
+            Some(&OPEN) => {
+                pos = ahead;
+                visited.insert(pos);
+            }
+            _ => (dir, phase) = policy.turn(dir, phase),
+        }
+
+        if !seen.insert((pos, dir, phase)) {
+            return (visited, true);
+        }
+    }
+}
+
+/// both answers the slow way, for cross-checking the real solution
+///
+/// part 1 walks every guard step by step. part 2 puts an obstruction on
+/// every open cell of a copy of the map in turn, not just the visited ones,
+/// and walks every guard again
+pub fn brute_force(lab: &Lab) -> (usize, usize) {
+    let visited: HashSet<Point> = lab
+        .guards
+        .iter()
+        .flat_map(|&guard| naive_walk(&lab.grid, guard, &lab.policy, lab.edge).0)
+        .collect();
+
+    let mut loops = 0;
+    for (pos, &ch) in lab.grid.iter() {
+        if ch != OPEN || lab.guards.iter().any(|guard| guard.pos == pos) {
+            continue;
+        }
+
+        let mut grid = lab.grid.clone();
+        grid.set(pos, OBSTRUCTION);
+        if lab
+            .guards
+            .iter()
+            .any(|&guard| naive_walk(&grid, guard, &lab.policy, lab.edge).1)
+        {
+            loops += 1;
+        }
+    }
+
+    (visited.len(), loops)
+}
+
+/// true when `guard` leaves the grid, checked step by step
+pub fn guard_exits(grid: &Grid<char>, guard: Guard, policy: &TurnPolicy, edge: EdgeMode) -> bool {
+    !naive_walk(grid, guard, policy, edge).1
+}
@@ -0,0 +1,109 @@
+use grid::{Direction, Grid, Point};
+
+use crate::{EdgeMode, Guard, JumpTable, LoopChecker, Simulation, State, TurnPolicy};
+
+/// where the guard is and which way they face after `step` moves and turns
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PathStep {
+    pub step: usize,
+    pub pos: Point,
+    pub dir: Direction,
+}
+
+/// how a patrol ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PathEnd {
+    /// the guard stepped off the grid after the last step
+    Exited,
+    /// the last step repeats the state at `cycle_start`
+    Looped { cycle_start: usize },
+}
+
+/// every step of a patrol in order, starting with the guard's start as
+/// step 0
+#[derive(Debug, Clone)]
+pub struct GuardPath {
+    pub steps: Vec<PathStep>,
+    pub end: PathEnd,
+}
+
+impl GuardPath {
+    /// the steps the guard repeats forever, None when they leave the grid
+    pub fn cycle(&self) -> Option<&[PathStep]> {
+        match self.end {
+            PathEnd::Exited => None,
+            PathEnd::Looped { cycle_start } => Some(&self.steps[cycle_start..self.steps.len() - 1]),
+        }
+    }
+}
+
+/// a part 2 obstruction and the cycle it traps a guard in
+#[derive(Debug, Clone)]
+pub struct LoopCycle {
+    pub obstruction: Point,
+    /// the index of the trapped guard, the first one when several are
+    pub guard: usize,
+    pub cycle: Vec<PathStep>,
+}
+
+/// walks `guard` with an optional extra obstruction and records every step
+pub fn get_path(
+    grid: &Grid<char>,
+    guard: Guard,
+    policy: &TurnPolicy,
+    edge: EdgeMode,
+    obstruction: Option<Point>,
+) -> GuardPath {
+    let mut sim = Simulation::new(grid, guard, policy, edge, obstruction);
+    let end = match sim.run() {
+        State::Looped { cycle_len } => PathEnd::Looped {
+            cycle_start: sim.steps() - cycle_len,
+        },
+        _ => PathEnd::Exited,
+    };
+
+    let steps = sim
+        .history()
+        .iter()
+        .enumerate()
+        .map(|(step, &(pos, dir))| PathStep { step, pos, dir })
+        .collect();
+
+    GuardPath { steps, end }
+}
+
+/// the cycle for every obstruction counted by part 2, in reading order of
+/// the obstructions
+///
+/// the jump table finds the looping obstructions, then only those are
+/// walked step by step to record their cycles
+pub fn get_loop_cycles(
+    grid: &Grid<char>,
+    guards: &[Guard],
+    policy: &TurnPolicy,
+    edge: EdgeMode,
+) -> Vec<LoopCycle> {
+    let table = JumpTable::new(grid, edge);
+    let mut checker = LoopChecker::new(&table, policy);
+    let mut candidates: Vec<Point> = crate::get_candidate_positions(grid, guards, policy, edge)
+        .into_iter()
+        .collect();
+
+    candidates.sort_by_key(|pos| (pos.y, pos.x));
+
+    candidates
+        .into_iter()
+        .filter_map(|obstruction| {
+            let guard = guards
+                .iter()
+                .position(|&guard| checker.loops_with(guard, obstruction))?;
+            let path = get_path(grid, guards[guard], policy, edge, Some(obstruction));
+
+            Some(LoopCycle {
+                obstruction,
+                guard,
+                cycle: path.cycle().unwrap_or_default().to_vec(),
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,182 @@
+use std::str::FromStr;
+
+use grid::{Direction, Grid, Point};
+
+use crate::is_visitable;
+
+/// where a guard starts and which way they face
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Guard {
+    pub pos: Point,
+    pub dir: Direction,
+}
+
+impl Guard {
+    pub fn new(pos: Point, dir: Direction) -> Self {
+        Guard { pos, dir }
+    }
+}
+
+/// a single change of heading in front of an obstruction
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Turn {
+    Right,
+    Left,
+    Reverse,
+}
+
+impl Turn {
+    pub fn apply(self, dir: Direction) -> Direction {
+        match self {
+            Turn::Right => dir.turn_right(),
+            Turn::Left => dir.turn_left(),
+            Turn::Reverse => dir.reverse(),
+        }
+    }
+}
+
+/// How a guard turns each time they bump into an obstruction
+///
+/// The turns are used in order and start over after the last one, so the
+/// puzzle's guard is the single turn `[Right]`. A guard's next move depends
+/// on how far through the sequence they are, the `phase`, as well as on
+/// their position and heading.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TurnPolicy {
+    turns: Vec<Turn>,
+}
+
+impl TurnPolicy {
+    /// always turn right, as in the puzzle
+    pub fn right() -> Self {
+        TurnPolicy {
+            turns: vec![Turn::Right],
+        }
+    }
+    pub fn left() -> Self {
+        TurnPolicy {
+            turns: vec![Turn::Left],
+        }
+    }
+    pub fn reverse() -> Self {
+        TurnPolicy {
+            turns: vec![Turn::Reverse],
+        }
+    }
+    /// cycles through `turns`, None when there are none
+    pub fn sequence(turns: Vec<Turn>) -> Option<Self> {
+        (!turns.is_empty()).then_some(TurnPolicy { turns })
+    }
+    /// how many turns before the sequence repeats
+    pub fn period(&self) -> usize {
+        self.turns.len()
+    }
+    /// the heading after turning at `phase`, and the phase for the next turn
+    pub fn turn(&self, dir: Direction, phase: usize) -> (Direction, usize) {
+        let dir = self.turns[phase].apply(dir);
+
+        (dir, (phase + 1) % self.turns.len())
+    }
+}
+
+impl Default for TurnPolicy {
+    fn default() -> Self {
+        TurnPolicy::right()
+    }
+}
+
+impl FromStr for TurnPolicy {
+    type Err = String;
+
+    /// `right`, `left`, `reverse`, or a sequence of `R`, `L` and `B` (back)
+    /// such as `RRL`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "right" => return Ok(TurnPolicy::right()),
+            "left" => return Ok(TurnPolicy::left()),
+            "reverse" => return Ok(TurnPolicy::reverse()),
+            _ => {}
+        }
+
+        let turns = s
+            .chars()
+            .map(|ch| match ch.to_ascii_uppercase() {
+                'R' => Ok(Turn::Right),
+                'L' => Ok(Turn::Left),
+                'B' => Ok(Turn::Reverse),
+                _ => Err(format!("invalid turn policy: {s}")),
+            })
+            .collect::<Result<Vec<Turn>, String>>()?;
+
+        TurnPolicy::sequence(turns).ok_or_else(|| String::from("empty turn policy"))
+    }
+}
+
+/// What happens when a guard walks over the edge of the map
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum EdgeMode {
+    /// they leave the map and the patrol is over, as in the puzzle
+    #[default]
+    Exit,
+    /// they come back in on the opposite edge, the map is a torus
+    Wrap,
+    /// the edge blocks them like an obstruction
+    Wall,
+}
+
+/// what a guard finds in front of them
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Ahead {
+    /// open floor they can step onto
+    Open(Point),
+    /// an obstruction, or a wall at the edge, they have to turn
+    Blocked,
+    /// the edge of the map, they leave
+    Off,
+}
+
+impl EdgeMode {
+    /// the cell in front of the guard at `pos` facing `dir`
+    pub fn ahead(self, grid: &Grid<char>, pos: Point, dir: Direction) -> Ahead {
+        let mut next = pos.step(dir);
+
+        if !grid.contains(next) {
+            match self {
+                EdgeMode::Exit => return Ahead::Off,
+                EdgeMode::Wall => return Ahead::Blocked,
+                EdgeMode::Wrap => next = self.wrap(grid, next),
+            }
+        }
+
+        match grid.get(next) {
+            Some(&ch) if is_visitable(ch) => Ahead::Open(next),
+            _ => Ahead::Blocked,
+        }
+    }
+    /// brings a point just off the map back in on the opposite edge when
+    /// wrapping, leaves it alone otherwise
+    pub fn wrap(self, grid: &Grid<char>, pos: Point) -> Point {
+        match self {
+            EdgeMode::Wrap => Point::new(
+                pos.x.rem_euclid(grid.width() as isize),
+                pos.y.rem_euclid(grid.height() as isize),
+            ),
+            _ => pos,
+        }
+    }
+}
+
+impl FromStr for EdgeMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "exit" => Ok(EdgeMode::Exit),
+            "wrap" => Ok(EdgeMode::Wrap),
+            "wall" => Ok(EdgeMode::Wall),
+            _ => Err(format!(
+                "invalid edge mode: {s}, expected exit, wrap or wall"
+            )),
+        }
+    }
+}
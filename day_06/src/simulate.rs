@@ -0,0 +1,218 @@
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet};
+
+use grid::{Direction, Grid, Point};
+
+use crate::{guard_glyph, Ahead, EdgeMode, Guard, TurnPolicy};
+
+/// the tile drawn for the extra obstruction, as in the puzzle text
+pub const EXTRA_OBSTRUCTION: char = 'O';
+/// a cell the guard walked through
+pub const VISITED: char = 'X';
+/// a cell where the guard turned
+pub const TURN: char = '+';
+/// a cell on the loop cycle when the output has no colours
+pub const CYCLE: char = '*';
+
+/// how far along the patrol is
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum State {
+    Walking,
+    /// the guard stepped off the grid
+    Exited,
+    /// the guard repeated a state, `cycle_len` steps after first reaching it
+    Looped {
+        cycle_len: usize,
+    },
+}
+
+/// how frames are drawn
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Style {
+    /// bare characters, the loop cycle is drawn with `CYCLE`
+    Plain,
+    /// ANSI colours, the loop cycle keeps its glyphs on a red background
+    Ansi,
+}
+
+/// A step by step replay of the guard's patrol
+///
+/// Every call to `step` either moves the guard one cell or turns them, the
+/// same way `get_distinct_visited_positions` walks, so each step can be drawn
+/// as a frame.
+pub struct Simulation<'a> {
+    grid: &'a Grid<char>,
+    policy: &'a TurnPolicy,
+    edge: EdgeMode,
+    obstruction: Option<Point>,
+    pos: Point,
+    dir: Direction,
+    phase: usize,
+    visited: HashSet<Point>,
+    turns: HashSet<Point>,
+    /// every (position, heading) so far, the start is step 0
+    history: Vec<(Point, Direction)>,
+    /// the step each (position, heading, phase) state was first reached
+    seen: HashMap<(Point, Direction, usize), usize>,
+    cycle: HashSet<Point>,
+    state: State,
+}
+
+impl<'a> Simulation<'a> {
+    /// a patrol by `guard`, with an optional extra obstruction
+    pub fn new(
+        grid: &'a Grid<char>,
+        guard: Guard,
+        policy: &'a TurnPolicy,
+        edge: EdgeMode,
+        obstruction: Option<Point>,
+    ) -> Self {
+        let Guard { pos, dir } = guard;
+
+        Simulation {
+            grid,
+            policy,
+            edge,
+            obstruction,
+            pos,
+            dir,
+            phase: 0,
+            visited: HashSet::from([pos]),
+            turns: HashSet::new(),
+            history: vec![(pos, dir)],
+            seen: HashMap::from([((pos, dir, 0), 0)]),
+            cycle: HashSet::new(),
+            state: State::Walking,
+        }
+    }
+    pub fn state(&self) -> State {
+        self.state
+    }
+    /// the number of moves and turns made so far
+    pub fn steps(&self) -> usize {
+        self.history.len() - 1
+    }
+    pub fn position(&self) -> Point {
+        self.pos
+    }
+    pub fn direction(&self) -> Direction {
+        self.dir
+    }
+    /// every (position, heading) so far, the start is step 0
+    pub fn history(&self) -> &[(Point, Direction)] {
+        &self.history
+    }
+    pub fn visited(&self) -> &HashSet<Point> {
+        &self.visited
+    }
+    /// the cells on the loop cycle, empty until a loop is detected
+    pub fn cycle(&self) -> &HashSet<Point> {
+        &self.cycle
+    }
+    /// moves or turns the guard once, does nothing once the patrol is over
+    pub fn step(&mut self) -> State {
+        if self.state != State::Walking {
+            return self.state;
+        }
+
+        match self.edge.ahead(self.grid, self.pos, self.dir) {
+            Ahead::Off => {
+                self.state = State::Exited;
+                return self.state;
+            }
+            Ahead::Open(next) if self.obstruction != Some(next) => {
+                self.pos = next;
+                self.visited.insert(next);
+            }
+            _ => {
+                (self.dir, self.phase) = self.policy.turn(self.dir, self.phase);
+                self.turns.insert(self.pos);
+            }
+        }
+
+        let step = self.history.len();
+        self.history.push((self.pos, self.dir));
+
+        match self.seen.entry((self.pos, self.dir, self.phase)) {
+            Entry::Occupied(first) => {
+                let first = *first.get();
+                self.cycle = self.history[first..].iter().map(|&(p, _)| p).collect();
+                self.state = State::Looped {
+                    cycle_len: step - first,
+                };
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(step);
+            }
+        }
+
+        self.state
+    }
+    /// steps until the guard leaves or loops
+    pub fn run(&mut self) -> State {
+        while self.step() == State::Walking {}
+
+        self.state
+    }
+    /// draws the grid with the guard, the visited cells, the turns and,
+    /// once detected, the loop cycle
+    pub fn render(&self, style: Style) -> String {
+        let mut frame = String::new();
+
+        for y in 0..self.grid.height() {
+            for x in 0..self.grid.width() {
+                let pos = Point::new(x as isize, y as isize);
+                self.render_cell(&mut frame, pos, style);
+            }
+            frame.push('\n');
+        }
+
+        frame
+    }
+    fn render_cell(&self, frame: &mut String, pos: Point, style: Style) {
+        let on_cycle = self.cycle.contains(&pos);
+        let (glyph, colour) = if pos == self.pos && self.state != State::Exited {
+            (guard_glyph(self.dir), "1;32")
+        } else if self.obstruction == Some(pos) {
+            (EXTRA_OBSTRUCTION, "1;35")
+        } else if self.turns.contains(&pos) {
+            (TURN, "36")
+        } else if self.visited.contains(&pos) {
+            (VISITED, "33")
+        } else {
+            (self.grid.get(pos).copied().unwrap_or(' '), "")
+        };
+
+        match style {
+            Style::Plain if on_cycle && glyph == VISITED => frame.push(CYCLE),
+            Style::Plain => frame.push(glyph),
+            Style::Ansi if on_cycle => frame.push_str(&format!("\x1b[{colour};41m{glyph}\x1b[0m")),
+            Style::Ansi if colour.is_empty() => frame.push(glyph),
+            Style::Ansi => frame.push_str(&format!("\x1b[{colour}m{glyph}\x1b[0m")),
+        }
+    }
+    /// a one line summary of where the patrol is
+    pub fn status(&self) -> String {
+        let Point { x, y } = self.pos;
+
+        match self.state {
+            State::Walking => format!(
+                "step {}: guard at ({x}, {y}) facing {}, {} cells visited",
+                self.steps(),
+                self.dir,
+                self.visited.len()
+            ),
+            State::Exited => format!(
+                "step {}: guard left the map from ({x}, {y}) facing {}, {} cells visited",
+                self.steps(),
+                self.dir,
+                self.visited.len()
+            ),
+            State::Looped { cycle_len } => format!(
+                "step {}: guard is stuck in a loop of {cycle_len} steps through {} cells",
+                self.steps(),
+                self.cycle.len()
+            ),
+        }
+    }
+}
@@ -0,0 +1,119 @@
+// the fewest obstructions that trap a guard must match trying every set of
+// obstructions up to the same size, and the longest loops must come out in
+// the order `analyse` promises
+
+use common::Solution;
+use day_06::{
+    best_loop_obstructions, min_trap_obstructions, oracle, Day06, Lab, OBSTRUCTION, OPEN,
+};
+use grid::Point;
+
+const MAX_OBSTRUCTIONS: usize = 2;
+
+/// true when adding `obstructions` leaves some guard walking forever,
+/// checked with the oracle's plain walk
+fn traps(lab: &Lab, obstructions: &[Point]) -> bool {
+    let mut grid = lab.grid.clone();
+    for &pos in obstructions {
+        grid.set(pos, OBSTRUCTION);
+    }
+
+    lab.guards
+        .iter()
+        .any(|&guard| !oracle::guard_exits(&grid, guard, &lab.policy, lab.edge))
+}
+
+/// the size of the smallest set of up to `max` open cells that traps a
+/// guard, trying every set in turn
+fn brute_force_min(lab: &Lab, max: usize) -> Option<usize> {
+    let cells: Vec<Point> = lab
+        .grid
+        .find_all(&OPEN)
+        .filter(|pos| lab.guards.iter().all(|guard| guard.pos != *pos))
+        .collect();
+
+    fn any_set(lab: &Lab, cells: &[Point], size: usize, chosen: &mut Vec<Point>) -> bool {
+        if size == 0 {
+            return traps(lab, chosen);
+        }
+        for (i, &pos) in cells.iter().enumerate() {
+            chosen.push(pos);
+            if any_set(lab, &cells[i + 1..], size - 1, chosen) {
+                return true;
+            }
+            chosen.pop();
+        }
+        false
+    }
+
+    (0..=max).find(|&size| any_set(lab, &cells, size, &mut Vec::new()))
+}
+
+#[test]
+fn min_trap_matches_brute_force() {
+    let mut sizes = [0; MAX_OBSTRUCTIONS + 2];
+
+    for seed in 0..200 {
+        let width = 4 + (seed % 3) as usize;
+        let height = 4 + (seed % 2) as usize;
+        let density = 0.05 + (seed % 4) as f64 * 0.05;
+        let Some(map) = day_06::generate_map(width, height, density, seed) else {
+            continue;
+        };
+        let lab = Day06::parse(&map).unwrap();
+
+        let expected = brute_force_min(&lab, MAX_OBSTRUCTIONS);
+        let found = min_trap_obstructions(
+            &lab.grid,
+            &lab.guards,
+            &lab.policy,
+            lab.edge,
+            MAX_OBSTRUCTIONS,
+        );
+
+        assert_eq!(found.as_ref().map(Vec::len), expected, "seed {seed}\n{map}");
+        if let Some(obstructions) = found {
+            assert!(traps(&lab, &obstructions), "seed {seed}\n{map}");
+        }
+        sizes[expected.unwrap_or(MAX_OBSTRUCTIONS + 1)] += 1;
+    }
+
+    // the maps have to cover needing one and two obstructions, and none
+    // being enough, or the comparison proves little
+    assert!(sizes[1] > 0 && sizes[2] > 0 && sizes[3] > 0, "{sizes:?}");
+}
+
+#[test]
+fn min_trap_on_sample() {
+    let lab = Day06::parse(day_06::EXAMPLE).unwrap();
+    let found = min_trap_obstructions(&lab.grid, &lab.guards, &lab.policy, lab.edge, 2);
+
+    // the first single obstruction in reading order that makes a loop
+    assert_eq!(found, Some(vec![Point::new(3, 6)]));
+}
+
+#[test]
+fn best_loops_longest_first_then_reading_order() {
+    let lab = Day06::parse(day_06::EXAMPLE).unwrap();
+    let best = best_loop_obstructions(&lab.grid, &lab.guards, &lab.policy, lab.edge, 6);
+    let ranked: Vec<(Point, usize)> = best
+        .iter()
+        .map(|cycle| (cycle.obstruction, cycle.cycle.len()))
+        .collect();
+
+    assert_eq!(
+        ranked,
+        [
+            (Point::new(3, 8), 42),
+            (Point::new(3, 6), 22),
+            (Point::new(1, 8), 20),
+            (Point::new(7, 9), 18),
+            (Point::new(6, 7), 16),
+            (Point::new(7, 7), 16),
+        ]
+    );
+    assert_eq!(
+        best_loop_obstructions(&lab.grid, &lab.guards, &lab.policy, lab.edge, 2).len(),
+        2
+    );
+}
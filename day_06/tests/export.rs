@@ -0,0 +1,108 @@
+use common::Solution;
+use day_06::{export, get_path, Day06, LoopCycle, PathEnd, PathStep, EXAMPLE};
+use grid::{Direction, Point};
+
+#[test]
+fn sample_path_exits_south() {
+    let lab = Day06::parse(EXAMPLE).unwrap();
+    let path = get_path(&lab.grid, lab.guards[0], &lab.policy, lab.edge, None);
+
+    assert_eq!(path.end, PathEnd::Exited);
+    assert_eq!(path.steps.len(), 55);
+    assert_eq!(
+        path.steps.last(),
+        Some(&PathStep {
+            step: 54,
+            pos: Point::new(7, 9),
+            dir: Direction::S,
+        })
+    );
+    assert_eq!(path.cycle(), None);
+}
+
+#[test]
+fn sample_cycle_leaves_out_the_repeated_state() {
+    let lab = Day06::parse(EXAMPLE).unwrap();
+    let obstruction = Some(Point::new(3, 6));
+    let path = get_path(&lab.grid, lab.guards[0], &lab.policy, lab.edge, obstruction);
+
+    // the last step is back at the start, facing the same way
+    assert_eq!(path.end, PathEnd::Looped { cycle_start: 0 });
+    assert_eq!(path.steps.len(), 23);
+    let last = path.steps[22];
+    assert_eq!((last.pos, last.dir), (path.steps[0].pos, path.steps[0].dir));
+
+    let cycle = path.cycle().unwrap();
+    assert_eq!(cycle.len(), 22);
+    assert_eq!(cycle.first(), path.steps.first());
+    assert_eq!(
+        cycle.last(),
+        Some(&PathStep {
+            step: 21,
+            pos: Point::new(4, 6),
+            dir: Direction::W,
+        })
+    );
+}
+
+/// a guard that turns once in front of the obstruction and walks off the
+/// east edge, with a made up two step cycle
+fn tiny() -> (grid::Grid<char>, Vec<day_06::GuardPath>, Vec<LoopCycle>) {
+    let lab = Day06::parse("#.\n^.\n").unwrap();
+    let path = get_path(&lab.grid, lab.guards[0], &lab.policy, lab.edge, None);
+    let cycle = LoopCycle {
+        obstruction: Point::new(1, 0),
+        guard: 0,
+        cycle: vec![
+            PathStep {
+                step: 3,
+                pos: Point::new(0, 1),
+                dir: Direction::E,
+            },
+            PathStep {
+                step: 4,
+                pos: Point::new(1, 1),
+                dir: Direction::W,
+            },
+        ],
+    };
+
+    (lab.grid, vec![path], vec![cycle])
+}
+
+#[test]
+fn tiny_map_json() {
+    let (grid, paths, cycles) = tiny();
+
+    assert_eq!(
+        export::report_to_json(&grid, &paths, &cycles),
+        r#"{
+  "width": 2,
+  "height": 2,
+  "guards": [
+    {"end": "exited", "steps": [{"step": 0, "x": 0, "y": 1, "dir": "N"}, {"step": 1, "x": 0, "y": 1, "dir": "E"}, {"step": 2, "x": 1, "y": 1, "dir": "E"}]}
+  ],
+  "loops": [
+    {"obstruction": {"x": 1, "y": 0}, "guard": 1, "cycle": [{"step": 3, "x": 0, "y": 1, "dir": "E"}, {"step": 4, "x": 1, "y": 1, "dir": "W"}]}
+  ]
+}"#
+    );
+}
+
+#[test]
+fn tiny_map_svg() {
+    let (grid, paths, cycles) = tiny();
+
+    assert_eq!(
+        export::report_to_svg(&grid, &paths, &cycles),
+        r##"<svg xmlns="http://www.w3.org/2000/svg" width="20" height="20" viewBox="0 0 20 20">
+  <rect width="20" height="20" fill="#ffffff"/>
+  <rect x="0" y="0" width="10" height="10" fill="#333333"/>
+  <path d="M5,15 L15,15" fill="none" stroke="#1f77b4" stroke-width="2" stroke-linejoin="round"/>
+  <circle cx="5" cy="15" r="5" fill="#1f77b4"/>
+  <rect x="10" y="0" width="10" height="10" fill="#ff7f0e"/>
+  <path d="M5,15 L15,15 L5,15" fill="none" stroke="#d62728" stroke-width="1" stroke-dasharray="3,2" stroke-opacity="0.6"/>
+</svg>
+"##
+    );
+}
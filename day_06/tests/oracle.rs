@@ -0,0 +1,56 @@
+// the jump table loop detector must agree with the brute force oracle on
+// random maps of all sizes and densities, for every turn policy and edge
+// mode
+
+use common::Solution;
+use day_06::{oracle, Day06, EdgeMode};
+
+const MAPS: u64 = 1000;
+
+fn check_random_maps(policy: &str, edge: EdgeMode, maps: u64) {
+    for seed in 0..maps {
+        let width = 4 + (seed % 17) as usize;
+        let height = 4 + (seed % 13) as usize;
+        let density = 0.05 + (seed % 7) as f64 * 0.05;
+        let Some(map) = day_06::generate_map(width, height, density, seed) else {
+            continue;
+        };
+
+        let mut lab = Day06::parse(&map).unwrap();
+        lab.policy = policy.parse().unwrap();
+        lab.edge = edge;
+
+        let expected = oracle::brute_force(&lab);
+        let actual = (Day06::part1(&lab), Day06::part2(&lab));
+        assert_eq!(
+            actual, expected,
+            "seed {seed}, turning {policy}, {edge:?} edges\n{map}"
+        );
+    }
+}
+
+#[test]
+fn matches_oracle_turning_right() {
+    check_random_maps("right", EdgeMode::Exit, MAPS);
+}
+
+#[test]
+fn matches_oracle_with_other_turn_policies() {
+    for policy in ["left", "reverse", "RRL", "LB"] {
+        check_random_maps(policy, EdgeMode::Exit, MAPS / 4);
+    }
+}
+
+#[test]
+fn matches_oracle_with_wrapping_edges() {
+    for policy in ["right", "RRL"] {
+        check_random_maps(policy, EdgeMode::Wrap, MAPS / 8);
+    }
+}
+
+#[test]
+fn matches_oracle_with_walled_edges() {
+    for policy in ["right", "LB"] {
+        check_random_maps(policy, EdgeMode::Wall, MAPS / 8);
+    }
+}
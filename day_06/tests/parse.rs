@@ -0,0 +1,37 @@
+use common::Solution;
+use day_06::{Day06, Guard, EXAMPLE};
+use grid::{Direction, Point};
+
+#[test]
+fn finds_the_guard() {
+    let lab = Day06::parse(EXAMPLE).unwrap();
+
+    assert_eq!(lab.guards, [Guard::new(Point::new(4, 6), Direction::N)]);
+    assert_eq!(lab.grid.get(Point::new(4, 6)), Some(&day_06::OPEN));
+}
+
+#[test]
+fn finds_every_guard_in_reading_order() {
+    let lab = Day06::parse("..v\n<..\n...\n").unwrap();
+
+    assert_eq!(
+        lab.guards,
+        [
+            Guard::new(Point::new(2, 0), Direction::S),
+            Guard::new(Point::new(0, 1), Direction::W),
+        ]
+    );
+}
+
+/// a map without a guard would otherwise answer 0 and 0 as if it were fine
+#[test]
+fn map_without_a_guard_is_an_error() {
+    let err = Day06::parse("..#.\n....\n").err().unwrap();
+
+    assert_eq!((err.line(), err.column()), (1, 1));
+    assert_eq!(
+        err.message(),
+        "no guard on the map, expected one of ^ > v <"
+    );
+    assert!(Day06::parse("").is_err());
+}
@@ -0,0 +1,33 @@
+// the threaded part 2 must count exactly the same obstructions as the
+// single threaded path, however the candidates are split
+
+use std::path::Path;
+
+use common::Solution;
+use day_06::{Day06, EXAMPLE};
+
+fn check_thread_counts(input: &str) {
+    let lab = Day06::parse(input).unwrap();
+    let expected = day_06::part2_with_threads(&lab, 1);
+
+    for threads in 2..=8 {
+        assert_eq!(
+            day_06::part2_with_threads(&lab, threads),
+            expected,
+            "{threads} threads"
+        );
+    }
+}
+
+#[test]
+fn sample_matches_single_thread() {
+    check_thread_counts(EXAMPLE);
+}
+
+#[test]
+fn input_matches_single_thread() {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("input.txt");
+    let input = common::read_file(&path).unwrap();
+
+    check_thread_counts(&input);
+}
@@ -0,0 +1,373 @@
+//! Points, vectors and compass directions on a grid
+//!
+//! `x` grows to the right and `y` grows downwards, the same way rows and
+//! columns are laid out in a puzzle input. "Clockwise" and "right" therefore
+//! mean what they look like on screen: turning right from north faces east.
+
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A signed (x, y) position
+///
+/// Coordinates are signed so that stepping off the edge of a grid produces a
+/// point that simply fails the bounds check instead of wrapping around.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point {
+    pub x: isize,
+    pub y: isize,
+}
+
+/// A signed (dx, dy) displacement between two points
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Vector {
+    pub dx: isize,
+    pub dy: isize,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point::new(0, 0);
+
+    pub const fn new(x: isize, y: isize) -> Self {
+        Point { x, y }
+    }
+    /// the point one step away in `dir`
+    pub fn step(self, dir: Direction) -> Self {
+        self + dir.vector()
+    }
+    /// |dx| + |dy|, the number of 4-neighbour steps between the points
+    pub fn manhattan(self, other: Point) -> usize {
+        (other - self).manhattan_len()
+    }
+    /// max(|dx|, |dy|), the number of 8-neighbour steps between the points
+    pub fn chebyshev(self, other: Point) -> usize {
+        (other - self).chebyshev_len()
+    }
+}
+
+impl Vector {
+    pub const ZERO: Vector = Vector::new(0, 0);
+
+    pub const fn new(dx: isize, dy: isize) -> Self {
+        Vector { dx, dy }
+    }
+    /// a quarter turn clockwise, (dx, dy) -> (-dy, dx)
+    pub const fn rotated_cw(self) -> Self {
+        Vector::new(-self.dy, self.dx)
+    }
+    /// a quarter turn counter-clockwise, (dx, dy) -> (dy, -dx)
+    pub const fn rotated_ccw(self) -> Self {
+        Vector::new(self.dy, -self.dx)
+    }
+    /// a half turn, the same in either sense
+    pub const fn rotated_180(self) -> Self {
+        Vector::new(-self.dx, -self.dy)
+    }
+    /// `quarter_turns` quarter turns, positive is clockwise
+    pub const fn rotated(self, quarter_turns: i32) -> Self {
+        match quarter_turns.rem_euclid(4) {
+            1 => self.rotated_cw(),
+            2 => self.rotated_180(),
+            3 => self.rotated_ccw(),
+            _ => self,
+        }
+    }
+    /// mirrors left and right, (dx, dy) -> (-dx, dy)
+    pub const fn reflected_x(self) -> Self {
+        Vector::new(-self.dx, self.dy)
+    }
+    /// mirrors up and down, (dx, dy) -> (dx, -dy)
+    pub const fn reflected_y(self) -> Self {
+        Vector::new(self.dx, -self.dy)
+    }
+    /// mirrors across the down-right diagonal, (dx, dy) -> (dy, dx)
+    pub const fn transposed(self) -> Self {
+        Vector::new(self.dy, self.dx)
+    }
+    pub const fn manhattan_len(self) -> usize {
+        self.dx.unsigned_abs() + self.dy.unsigned_abs()
+    }
+    pub const fn chebyshev_len(self) -> usize {
+        let dx = self.dx.unsigned_abs();
+        let dy = self.dy.unsigned_abs();
+
+        if dx > dy {
+            dx
+        } else {
+            dy
+        }
+    }
+}
+
+impl Add<Vector> for Point {
+    type Output = Point;
+
+    fn add(self, rhs: Vector) -> Point {
+        Point::new(self.x + rhs.dx, self.y + rhs.dy)
+    }
+}
+
+impl AddAssign<Vector> for Point {
+    fn add_assign(&mut self, rhs: Vector) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub<Vector> for Point {
+    type Output = Point;
+
+    fn sub(self, rhs: Vector) -> Point {
+        Point::new(self.x - rhs.dx, self.y - rhs.dy)
+    }
+}
+
+impl SubAssign<Vector> for Point {
+    fn sub_assign(&mut self, rhs: Vector) {
+        *self = *self - rhs;
+    }
+}
+
+impl Sub for Point {
+    type Output = Vector;
+
+    fn sub(self, rhs: Point) -> Vector {
+        Vector::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl Add for Vector {
+    type Output = Vector;
+
+    fn add(self, rhs: Vector) -> Vector {
+        Vector::new(self.dx + rhs.dx, self.dy + rhs.dy)
+    }
+}
+
+impl Sub for Vector {
+    type Output = Vector;
+
+    fn sub(self, rhs: Vector) -> Vector {
+        Vector::new(self.dx - rhs.dx, self.dy - rhs.dy)
+    }
+}
+
+impl Mul<isize> for Vector {
+    type Output = Vector;
+
+    fn mul(self, rhs: isize) -> Vector {
+        Vector::new(self.dx * rhs, self.dy * rhs)
+    }
+}
+
+impl Neg for Vector {
+    type Output = Vector;
+
+    fn neg(self) -> Vector {
+        self.rotated_180()
+    }
+}
+
+impl From<Direction> for Vector {
+    fn from(dir: Direction) -> Self {
+        dir.vector()
+    }
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+/// The eight compass directions, listed clockwise from north
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+impl Direction {
+    /// every direction, clockwise from north
+    pub const ALL: [Direction; 8] = [
+        Direction::N,
+        Direction::NE,
+        Direction::E,
+        Direction::SE,
+        Direction::S,
+        Direction::SW,
+        Direction::W,
+        Direction::NW,
+    ];
+    /// north, east, south and west
+    pub const CARDINAL: [Direction; 4] = [Direction::N, Direction::E, Direction::S, Direction::W];
+    /// the four diagonals
+    pub const ORDINAL: [Direction; 4] =
+        [Direction::NE, Direction::SE, Direction::SW, Direction::NW];
+
+    /// the unit step for this direction
+    pub const fn vector(self) -> Vector {
+        match self {
+            Direction::N => Vector::new(0, -1),
+            Direction::NE => Vector::new(1, -1),
+            Direction::E => Vector::new(1, 0),
+            Direction::SE => Vector::new(1, 1),
+            Direction::S => Vector::new(0, 1),
+            Direction::SW => Vector::new(-1, 1),
+            Direction::W => Vector::new(-1, 0),
+            Direction::NW => Vector::new(-1, -1),
+        }
+    }
+    /// the direction with the given unit step, if there is one
+    pub fn from_vector(v: Vector) -> Option<Self> {
+        Direction::ALL.into_iter().find(|dir| dir.vector() == v)
+    }
+    /// rotates by `eighths` eighth turns, positive is clockwise
+    pub fn rotated(self, eighths: i32) -> Self {
+        let index = (self as i32 + eighths).rem_euclid(8);
+
+        Direction::ALL[index as usize]
+    }
+    /// a quarter turn clockwise
+    pub fn turn_right(self) -> Self {
+        self.rotated(2)
+    }
+    /// a quarter turn counter-clockwise
+    pub fn turn_left(self) -> Self {
+        self.rotated(-2)
+    }
+    /// the opposite direction
+    pub fn reverse(self) -> Self {
+        self.rotated(4)
+    }
+    pub fn is_cardinal(self) -> bool {
+        (self as u8).is_multiple_of(2)
+    }
+    /// the short compass name, e.g. "NE"
+    pub fn name(self) -> &'static str {
+        match self {
+            Direction::N => "N",
+            Direction::NE => "NE",
+            Direction::E => "E",
+            Direction::SE => "SE",
+            Direction::S => "S",
+            Direction::SW => "SW",
+            Direction::W => "W",
+            Direction::NW => "NW",
+        }
+    }
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NORTH: Vector = Vector::new(0, -1);
+    const EAST: Vector = Vector::new(1, 0);
+
+    #[test]
+    fn clockwise_is_clockwise_on_screen() {
+        // with y growing downwards a right turn from north faces east
+        assert_eq!(NORTH.rotated_cw(), EAST);
+        assert_eq!(EAST.rotated_cw(), Vector::new(0, 1));
+        assert_eq!(EAST.rotated_ccw(), NORTH);
+        assert_eq!(Direction::N.turn_right(), Direction::E);
+        assert_eq!(Direction::N.turn_left(), Direction::W);
+        assert_eq!(
+            Direction::from_vector(Direction::SW.vector().rotated_cw()),
+            Some(Direction::NW)
+        );
+    }
+
+    #[test]
+    fn rotations_compose() {
+        let v = Vector::new(3, -1);
+
+        assert_eq!(v.rotated_cw().rotated_ccw(), v);
+        assert_eq!(v.rotated_cw().rotated_cw(), v.rotated_180());
+        assert_eq!(v.rotated_180(), -v);
+        assert_eq!(v.rotated(1), v.rotated_cw());
+        assert_eq!(v.rotated(-1), v.rotated_ccw());
+        assert_eq!(v.rotated(-2), v.rotated_180());
+        assert_eq!(v.rotated(5), v.rotated_cw());
+        assert_eq!(v.rotated(-7), v.rotated_cw());
+        assert_eq!(v.rotated(4), v);
+        assert_eq!(v.rotated(0), v);
+    }
+
+    #[test]
+    fn direction_rotations() {
+        assert_eq!(Direction::N.rotated(1), Direction::NE);
+        assert_eq!(Direction::N.rotated(-1), Direction::NW);
+        assert_eq!(Direction::W.rotated(3), Direction::NE);
+        assert_eq!(Direction::E.rotated(-10), Direction::N);
+        assert_eq!(Direction::SE.rotated(16), Direction::SE);
+        assert_eq!(Direction::NE.reverse(), Direction::SW);
+
+        // a direction rotated by a quarter turn matches its vector rotated
+        for dir in Direction::ALL {
+            let turned = Direction::from_vector(dir.vector().rotated_cw());
+            assert_eq!(turned, Some(dir.turn_right()), "{dir}");
+            let turned = Direction::from_vector(dir.vector().rotated(-1));
+            assert_eq!(turned, Some(dir.turn_left()), "{dir}");
+        }
+    }
+
+    #[test]
+    fn reflections() {
+        let v = Vector::new(2, -5);
+
+        assert_eq!(v.reflected_x(), Vector::new(-2, -5));
+        assert_eq!(v.reflected_y(), Vector::new(2, 5));
+        assert_eq!(v.transposed(), Vector::new(-5, 2));
+        assert_eq!(v.reflected_x().reflected_y(), v.rotated_180());
+        // mirroring across the diagonal then left to right is a quarter turn
+        assert_eq!(v.transposed().reflected_x(), v.rotated_cw());
+    }
+
+    #[test]
+    fn distances() {
+        let a = Point::new(1, 2);
+        let b = Point::new(-3, 5);
+
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(b.manhattan(a), 7);
+        assert_eq!(a.chebyshev(b), 4);
+        assert_eq!(a.chebyshev(Point::new(2, -4)), 6);
+        assert_eq!(a.manhattan(a), 0);
+        assert_eq!(Vector::ZERO.chebyshev_len(), 0);
+    }
+
+    #[test]
+    fn from_vector_only_takes_unit_steps() {
+        for dir in Direction::ALL {
+            assert_eq!(Direction::from_vector(dir.vector()), Some(dir));
+        }
+        assert_eq!(Direction::from_vector(Vector::new(2, 0)), None);
+        assert_eq!(Direction::from_vector(Vector::ZERO), None);
+    }
+
+    #[test]
+    fn points_and_vectors() {
+        let mut p = Point::new(4, 4);
+
+        assert_eq!(p.step(Direction::NW), Point::new(3, 3));
+        assert_eq!(Point::new(7, 1) - p, Vector::new(3, -3));
+        p += Direction::S.vector() * 3;
+        assert_eq!(p, Point::new(4, 7));
+        p -= Vector::new(4, 7);
+        assert_eq!(p, Point::ORIGIN);
+        assert!(Direction::E.is_cardinal());
+        assert!(!Direction::SW.is_cardinal());
+    }
+}
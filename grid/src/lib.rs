@@ -0,0 +1,356 @@
+use std::fmt;
+
+use common::ParseError;
+
+pub mod geometry;
+
+pub use geometry::{Direction, Point, Vector};
+
+/// A rectangular 2D grid stored row by row in a single `Vec`
+///
+/// Every lookup takes a signed `Point` and is bounds checked, so callers can
+/// step in any direction and let `None` tell them they left the grid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// builds a grid from row-major cells
+    ///
+    /// panics when `cells` does not hold exactly `width * height` values
+    pub fn from_cells(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(cells.len(), width * height, "cell count must match size");
+
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+    /// builds a grid from a list of rows
+    ///
+    /// panics when the rows are not all the same length
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+        let mut cells = Vec::with_capacity(width * height);
+
+        for row in rows {
+            assert_eq!(row.len(), width, "rows must all be the same length");
+            cells.extend(row);
+        }
+
+        Grid::from_cells(width, height, cells)
+    }
+    /// parses one row per line, converting each character with `cell`
+    ///
+    /// fails on rows of a different length than the first one and on
+    /// characters `cell` does not accept
+    pub fn parse_with<F>(input: &str, cell: F) -> Result<Self, ParseError>
+    where
+        F: Fn(char) -> Option<T>,
+    {
+        let mut width: Option<usize> = None;
+        let mut height: usize = 0;
+        let mut cells = Vec::new();
+
+        for line in common::lines(input) {
+            let row_width = line.text.chars().count();
+            match width {
+                None => width = Some(row_width),
+                Some(w) if w != row_width => {
+                    return Err(line.error(line.text, &format!("expected a row of width {w}")));
+                }
+                _ => {}
+            }
+
+            for (i, (offset, ch)) in line.text.char_indices().enumerate() {
+                match cell(ch) {
+                    Some(value) => cells.push(value),
+                    None => {
+                        let token = &line.text[offset..offset + ch.len_utf8()];
+                        return Err(ParseError::new(
+                            line.number,
+                            i + 1,
+                            token,
+                            "unexpected character",
+                        ));
+                    }
+                }
+            }
+            height += 1;
+        }
+
+        Ok(Grid::from_cells(width.unwrap_or(0), height, cells))
+    }
+    pub fn width(&self) -> usize {
+        self.width
+    }
+    pub fn height(&self) -> usize {
+        self.height
+    }
+    /// true when `p` lies inside the grid
+    pub fn contains(&self, p: Point) -> bool {
+        p.x >= 0 && p.y >= 0 && (p.x as usize) < self.width && (p.y as usize) < self.height
+    }
+    fn index_of(&self, p: Point) -> Option<usize> {
+        if self.contains(p) {
+            Some(p.y as usize * self.width + p.x as usize)
+        } else {
+            None
+        }
+    }
+    fn point_of(&self, index: usize) -> Point {
+        Point::new((index % self.width) as isize, (index / self.width) as isize)
+    }
+    pub fn get(&self, p: Point) -> Option<&T> {
+        self.index_of(p).map(|i| &self.cells[i])
+    }
+    pub fn get_mut(&mut self, p: Point) -> Option<&mut T> {
+        self.index_of(p).map(|i| &mut self.cells[i])
+    }
+    /// stores `value` at `p` and returns the previous value
+    /// returns None and leaves the grid untouched when `p` is out of bounds
+    pub fn set(&mut self, p: Point, value: T) -> Option<T> {
+        self.get_mut(p).map(|cell| std::mem::replace(cell, value))
+    }
+    /// every point in the grid, row by row
+    pub fn points(&self) -> impl Iterator<Item = Point> + '_ {
+        (0..self.cells.len()).map(|i| self.point_of(i))
+    }
+    /// every cell along with its point, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(i, cell)| (self.point_of(i), cell))
+    }
+    /// the in-bounds north, east, south and west neighbours of `p`
+    pub fn neighbours4(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        Direction::CARDINAL
+            .into_iter()
+            .map(move |dir| p.step(dir))
+            .filter(|n| self.contains(*n))
+    }
+    /// the in-bounds neighbours of `p` including diagonals
+    pub fn neighbours8(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        Direction::ALL
+            .into_iter()
+            .map(move |dir| p.step(dir))
+            .filter(|n| self.contains(*n))
+    }
+    /// the cells of row `y`, empty when `y` is out of bounds
+    pub fn row(&self, y: usize) -> impl Iterator<Item = &T> {
+        let cells = if y < self.height {
+            &self.cells[y * self.width..(y + 1) * self.width]
+        } else {
+            &[]
+        };
+
+        cells.iter()
+    }
+    /// the cells of column `x`, empty when `x` is out of bounds
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        let start = if x < self.width { x } else { self.cells.len() };
+
+        self.cells.iter().skip(start).step_by(self.width.max(1))
+    }
+    /// walks from `start` by `step` until leaving the grid, `start` itself
+    /// is the first item
+    pub fn ray(&self, start: Point, step: impl Into<Vector>) -> impl Iterator<Item = (Point, &T)> {
+        let step = step.into();
+
+        std::iter::successors(Some(start), move |p| Some(*p + step))
+            .map_while(move |p| self.get(p).map(|cell| (p, cell)))
+    }
+    /// the down-right diagonal starting at `start`
+    pub fn diagonal(&self, start: Point) -> impl Iterator<Item = (Point, &T)> {
+        self.ray(start, Direction::SE)
+    }
+    /// the down-left diagonal starting at `start`
+    pub fn anti_diagonal(&self, start: Point) -> impl Iterator<Item = (Point, &T)> {
+        self.ray(start, Direction::SW)
+    }
+}
+
+impl<T: PartialEq> Grid<T> {
+    /// the first point, row by row, holding `value`
+    pub fn find(&self, value: &T) -> Option<Point> {
+        self.cells
+            .iter()
+            .position(|cell| cell == value)
+            .map(|i| self.point_of(i))
+    }
+    /// every point holding `value`
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Point> + 'a {
+        self.iter()
+            .filter(move |(_, cell)| *cell == value)
+            .map(|(p, _)| p)
+    }
+    /// how many cells hold `value`
+    pub fn count(&self, value: &T) -> usize {
+        self.cells.iter().filter(|cell| *cell == value).count()
+    }
+}
+
+impl Grid<char> {
+    /// parses a grid of plain characters
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        Grid::parse_with(input, Some)
+    }
+}
+
+impl fmt::Display for Grid<char> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for y in 0..self.height {
+            let row: String = self.row(y).collect();
+            writeln!(f, "{row}")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "abc\ndef\n";
+
+    fn sample() -> Grid<char> {
+        Grid::parse(SAMPLE).unwrap()
+    }
+
+    #[test]
+    fn parse_and_display() {
+        let grid = sample();
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.get(Point::new(2, 1)), Some(&'f'));
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        assert_eq!(grid.get(Point::new(0, -1)), None);
+        assert_eq!(grid.to_string(), SAMPLE);
+    }
+
+    #[test]
+    fn parse_rejects_ragged_rows() {
+        let err = Grid::parse("abc\nde\nfgh").unwrap_err();
+
+        assert_eq!((err.line(), err.column()), (2, 1));
+        assert_eq!(err.token(), "de");
+        assert_eq!(err.message(), "expected a row of width 3");
+    }
+
+    #[test]
+    fn parse_with_reports_the_bad_character() {
+        let digit = |ch: char| ch.to_digit(10);
+        let err = Grid::parse_with("123\n4é6\n", digit).unwrap_err();
+
+        // the column counts characters, not bytes
+        assert_eq!((err.line(), err.column()), (2, 2));
+        assert_eq!(err.token(), "é");
+
+        let grid = Grid::parse_with("12\n34\n", digit).unwrap();
+        assert_eq!(grid.get(Point::new(1, 1)), Some(&4));
+    }
+
+    #[test]
+    fn parse_empty_input() {
+        let grid = Grid::parse("").unwrap();
+
+        assert_eq!((grid.width(), grid.height()), (0, 0));
+        assert_eq!(grid.points().count(), 0);
+    }
+
+    #[test]
+    fn set_returns_the_old_value() {
+        let mut grid = sample();
+
+        assert_eq!(grid.set(Point::new(1, 0), 'x'), Some('b'));
+        assert_eq!(grid.set(Point::new(5, 5), 'x'), None);
+        assert_eq!(grid.to_string(), "axc\ndef\n");
+    }
+
+    #[test]
+    fn neighbours_stay_in_bounds() {
+        let grid = Grid::from_cells(3, 3, vec![0; 9]);
+        let sorted = |points: Vec<Point>| {
+            let mut points = points;
+            points.sort();
+            points
+        };
+
+        let corner: Vec<Point> = grid.neighbours4(Point::new(0, 0)).collect();
+        assert_eq!(sorted(corner), [Point::new(0, 1), Point::new(1, 0)]);
+        let corner: Vec<Point> = grid.neighbours8(Point::new(2, 2)).collect();
+        assert_eq!(
+            sorted(corner),
+            [Point::new(1, 1), Point::new(1, 2), Point::new(2, 1)]
+        );
+
+        assert_eq!(grid.neighbours4(Point::new(1, 1)).count(), 4);
+        assert_eq!(grid.neighbours8(Point::new(1, 1)).count(), 8);
+        assert_eq!(grid.neighbours8(Point::new(1, 0)).count(), 5);
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid = sample();
+
+        assert_eq!(grid.row(1).collect::<String>(), "def");
+        assert_eq!(grid.row(2).count(), 0);
+        assert_eq!(grid.column(2).collect::<String>(), "cf");
+        assert_eq!(grid.column(3).count(), 0);
+    }
+
+    #[test]
+    fn rays_and_diagonals() {
+        let grid = Grid::parse("abcd\nefgh\nijkl\n").unwrap();
+        let cells = |ray: Vec<(Point, &char)>| ray.into_iter().map(|(_, &c)| c).collect::<String>();
+
+        assert_eq!(
+            cells(grid.ray(Point::new(1, 0), Direction::E).collect()),
+            "bcd"
+        );
+        assert_eq!(
+            cells(grid.ray(Point::new(3, 2), Vector::new(-2, -1)).collect()),
+            "lf"
+        );
+        assert_eq!(cells(grid.diagonal(Point::new(0, 0)).collect()), "afk");
+        assert_eq!(cells(grid.anti_diagonal(Point::new(3, 0)).collect()), "dgj");
+        assert_eq!(grid.ray(Point::new(4, 0), Direction::W).count(), 0);
+
+        let (start, _) = grid.diagonal(Point::new(1, 1)).next().unwrap();
+        assert_eq!(start, Point::new(1, 1));
+    }
+
+    #[test]
+    fn find_and_count() {
+        let grid = Grid::parse("a.b\n.a.\n").unwrap();
+
+        assert_eq!(grid.find(&'a'), Some(Point::new(0, 0)));
+        assert_eq!(grid.find(&'z'), None);
+        assert_eq!(
+            grid.find_all(&'.').collect::<Vec<_>>(),
+            [Point::new(1, 0), Point::new(0, 1), Point::new(2, 1)]
+        );
+        assert_eq!(grid.count(&'a'), 2);
+    }
+
+    #[test]
+    fn from_rows_matches_from_cells() {
+        let rows = Grid::from_rows(vec![vec![1, 2], vec![3, 4]]);
+        let cells = Grid::from_cells(2, 2, vec![1, 2, 3, 4]);
+
+        assert_eq!(rows, cells);
+    }
+
+    #[test]
+    #[should_panic(expected = "rows must all be the same length")]
+    fn from_rows_panics_on_ragged_rows() {
+        Grid::from_rows(vec![vec![1, 2], vec![3]]);
+    }
+}